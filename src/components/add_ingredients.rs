@@ -5,13 +5,26 @@ use dioxus::prelude::*;
 #[derive(PartialEq, Clone, Props)]
 pub struct ComponentProps {
     pub add_ingredient: EventHandler<Ingredient>,
+    pub keep_wasted: bool,
+    pub set_keep_wasted: EventHandler<bool>,
 }
 
 #[component]
 pub fn AddIngredients(props: ComponentProps) -> Element {
     use Ingredient::*;
     rsx! {
-        div { class: "col-span-full", "Add Ingredient" }
+        div { class: "col-span-full flex justify-between",
+            "Add Ingredient"
+            label {
+                class: "flex gap-2 whitespace-nowrap items-center justify-between",
+                "Keep wasted",
+                input {
+                    r#type: "checkbox",
+                    checked: "{props.keep_wasted}",
+                    onchange: move |_| props.set_keep_wasted.call(!props.keep_wasted),
+                }
+            }
+        }
         Button { onclick: move |_| props.add_ingredient.call(Cuke), "Cuke" }
         Button { onclick: move |_| props.add_ingredient.call(Banana), "Banana" }
         Button { onclick: move |_| props.add_ingredient.call(Paracetamol), "Paracetamol" }
@@ -1,9 +1,11 @@
 use crate::components::Button;
+use crate::mixer_map::MixerMap;
 use crate::sellable::{Effect, Product};
 use dioxus::prelude::*;
 
 #[derive(PartialEq, Clone, Props)]
 pub struct ComponentProps {
+    pub product: Product,
     pub set_working_product: EventHandler<Product>,
 }
 
@@ -18,5 +20,11 @@ pub fn BaseProducts(props: ComponentProps) -> Element {
         Button { onclick: move |_| props.set_working_product.call(Marijuana(Effect::Sedating)), "Granddaddy Purple" }
         Button { onclick: move |_| props.set_working_product.call(Meth), "Meth" }
         Button { onclick: move |_| props.set_working_product.call(Cocaine), "Cocaine" }
+        if !MixerMap::is_measured(props.product) {
+            div {
+                class: "col-span-full",
+                "The {props.product:?} mixer map is not measured yet. Mixing uses the reaction table seen in game, but the mix map and the geometric check stand in with marijuana's zones."
+            }
+        }
     }
 }
@@ -0,0 +1,30 @@
+use dioxus::prelude::*;
+
+#[derive(PartialEq, Clone, Props)]
+pub struct DepthInputProps {
+    pub depth: usize,
+    pub max: usize,
+    pub set_depth: EventHandler<usize>,
+}
+
+/// How many ingredients deep a search goes, kept between 1 and `max`.
+#[component]
+pub fn DepthInput(props: DepthInputProps) -> Element {
+    rsx! {
+        label {
+            class: "flex gap-2 whitespace-nowrap items-center",
+            "Depth",
+            input {
+                r#type: "number",
+                min: "1",
+                max: "{props.max}",
+                value: "{props.depth}",
+                oninput: move |event| {
+                    if let Ok(value) = event.value().parse::<usize>() {
+                        props.set_depth.call(value.clamp(1, props.max));
+                    }
+                },
+            }
+        }
+    }
+}
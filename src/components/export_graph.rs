@@ -0,0 +1,65 @@
+use crate::components::{Button, DepthInput};
+use crate::graph::StateGraph;
+use crate::price_book::PriceBook;
+use crate::sellable::Product;
+use dioxus::prelude::*;
+
+/// the text gets unwieldy past this, depth 3 is already thousands of nodes
+const MAX_DEPTH: usize = 3;
+
+#[derive(PartialEq, Clone, Props)]
+pub struct ComponentProps {
+    pub product: Product,
+    pub prices: PriceBook,
+}
+
+#[component]
+pub fn ExportGraph(props: ComponentProps) -> Element {
+    let mut depth = use_signal(|| 2);
+    let mut export = use_signal(|| None::<(&'static str, String)>);
+    let dot_prices = props.prices.clone();
+    let json_prices = props.prices.clone();
+    rsx! {
+        div { class: "col-span-full flex justify-between gap-2",
+            "Export Mix Graph"
+            DepthInput { depth: depth(), max: MAX_DEPTH, set_depth: move |value| depth.set(value) }
+        }
+        Button {
+            onclick: move |_| {
+                export.set(Some(("dot", StateGraph::build(props.product, depth(), &dot_prices).to_dot())))
+            },
+            "DOT"
+        }
+        Button {
+            onclick: move |_| {
+                export.set(Some(("json", StateGraph::build(props.product, depth(), &json_prices).to_json())))
+            },
+            "JSON"
+        }
+        if let Some((extension, text)) = export() {
+            a {
+                class: "col-span-full",
+                href: "data:text/plain;charset=utf-8,{percent_encode(&text)}",
+                download: "mix-graph.{extension}",
+                "Download mix-graph.{extension}"
+            }
+            textarea {
+                class: "col-span-full",
+                style: "height: 12rem; color: black;",
+                readonly: true,
+                value: "{text}",
+            }
+        }
+    }
+}
+
+fn percent_encode(text: &str) -> String {
+    text.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
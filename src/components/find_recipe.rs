@@ -0,0 +1,117 @@
+use crate::components::{Button, DepthInput};
+use crate::effect_set::EffectSet;
+use crate::finder::{find_recipes, Found};
+use crate::optimizer::{ingredient_cost, Goal, MAX_DEPTH};
+use crate::price_book::PriceBook;
+use crate::search::use_search;
+use crate::sellable::{Effect, Product, Sellable};
+use dioxus::prelude::*;
+
+#[derive(PartialEq, Clone, Props)]
+pub struct ComponentProps {
+    pub product: Product,
+    pub prices: PriceBook,
+    pub set_working_product: EventHandler<Sellable>,
+}
+
+#[component]
+pub fn FindRecipe(props: ComponentProps) -> Element {
+    let mut desired = use_signal(EffectSet::default);
+    let mut forbidden = use_signal(EffectSet::default);
+    let mut goal = use_signal(|| Goal::Cheapest);
+    let mut depth = use_signal(|| 3);
+    let mut search = use_search::<Found>();
+    let prices = props.prices.clone();
+    let unreached = search
+        .results
+        .read()
+        .as_ref()
+        .filter(|found| !search.is_running() && !found.unreached.is_empty())
+        .map(|found| {
+            found
+                .unreached
+                .iter()
+                .map(|effect| format!("{effect:?}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        });
+    rsx! {
+        div { class: "col-span-full flex justify-between gap-2",
+            "Find Recipe"
+            DepthInput { depth: depth(), max: MAX_DEPTH, set_depth: move |value| depth.set(value) }
+        }
+        div { class: "col-span-full", "Click an effect to want it, again to forbid it" }
+        for effect in Effect::ALL {
+            Button {
+                onclick: move |_| {
+                    // cycle through unset -> desired -> forbidden -> unset
+                    if desired.read().contains(&effect) {
+                        desired.write().remove(effect);
+                        forbidden.write().insert(effect);
+                    } else if forbidden.read().contains(&effect) {
+                        forbidden.write().remove(effect);
+                    } else {
+                        desired.write().insert(effect);
+                    }
+                },
+                active: desired.read().contains(&effect),
+                if forbidden.read().contains(&effect) {
+                    "not {effect:?}"
+                } else {
+                    "{effect:?}"
+                }
+            }
+        }
+        Button {
+            onclick: move |_| goal.set(Goal::Cheapest),
+            active: goal() == Goal::Cheapest,
+            "Cheapest"
+        }
+        Button {
+            onclick: move |_| goal.set(Goal::Shortest),
+            active: goal() == Goal::Shortest,
+            "Shortest"
+        }
+        Button {
+            onclick: move |_| {
+                if search.is_running() {
+                    search.cancel();
+                } else {
+                    search
+                        .start(
+                            find_recipes(
+                                props.product,
+                                desired(),
+                                forbidden(),
+                                goal(),
+                                prices.clone(),
+                                depth(),
+                                5,
+                            ),
+                        );
+                }
+            },
+            disabled: desired.read().is_empty() && !search.is_running(),
+            if let Some(progress) = (search.progress)() {
+                "Cancel ({progress * 100.:.0}%)"
+            } else {
+                "Find"
+            }
+        }
+        if let Some(unreached) = unreached {
+            div { class: "col-span-full", "No recipe reached {unreached}" }
+        }
+        {search.results.read().iter().flat_map(|found| &found.recipes).map(|recipe| {
+            let recipe_clone = recipe.clone();
+            let cost = ingredient_cost(recipe, &props.prices);
+            rsx! {
+                Button {
+                    key: "{recipe.key()}",
+                    onclick: move |_| props.set_working_product.call(recipe_clone.clone()),
+                    div { "{recipe.name}" }
+                    div { "{cost:.2} in ingredients" }
+                }
+            }
+        })}
+    }
+}
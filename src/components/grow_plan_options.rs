@@ -0,0 +1,74 @@
+use crate::price_book::PriceItem;
+use crate::sellable::{GrowPlan, MixState, OneTimeIngredient, Quality, Sellable};
+use dioxus::prelude::*;
+
+#[derive(PartialEq, Clone, Props)]
+pub struct ComponentProps {
+    pub set_grow_plan: EventHandler<GrowPlan>,
+    pub working_product: Sellable,
+    pub mix_state: MixState,
+}
+
+#[component]
+pub fn GrowPlanOptions(props: ComponentProps) -> Element {
+    let grow_plan = props.mix_state.grow_plan.clone();
+    let soil_prices = props.working_product.soil_prices(props.mix_state.clone());
+    rsx! {
+        div { class: "col-span-full", "Harvests per bag:" }
+        for soil_quality in [Quality::Low, Quality::Medium, Quality::High] {
+            label {
+                class: "col-span-full flex justify-between gap-2 whitespace-nowrap items-center",
+                "{PriceItem::Soil(soil_quality).label()}"
+                input {
+                    r#type: "number",
+                    min: "1",
+                    value: "{grow_plan.harvests(soil_quality)}",
+                    onchange: {
+                        let grow_plan = grow_plan.clone();
+                        move |event: Event<FormData>| {
+                            if let Ok(harvests) = event.value().parse::<u32>() {
+                                let mut grow_plan = grow_plan.clone();
+                                let harvests = harvests.max(1);
+                                match soil_quality {
+                                    Quality::Low => grow_plan.soil_harvests = harvests,
+                                    Quality::Medium => grow_plan.long_life_soil_harvests = harvests,
+                                    Quality::High => grow_plan.extra_long_life_soil_harvests = harvests,
+                                }
+                                props.set_grow_plan.call(grow_plan);
+                            }
+                        }
+                    },
+                }
+            }
+        }
+        for additive in [OneTimeIngredient::PGR, OneTimeIngredient::Fertilizer, OneTimeIngredient::SpeedGrow] {
+            label {
+                class: "col-span-full flex gap-2 whitespace-nowrap items-center justify-between",
+                "{PriceItem::Additive(additive).label()} every harvest",
+                input {
+                    r#type: "checkbox",
+                    checked: "{grow_plan.per_harvest.contains(&additive)}",
+                    onchange: {
+                        let grow_plan = grow_plan.clone();
+                        move |_| {
+                            let mut grow_plan = grow_plan.clone();
+                            if !grow_plan.per_harvest.remove(&additive) {
+                                grow_plan.per_harvest.insert(additive);
+                            }
+                            props.set_grow_plan.call(grow_plan);
+                        }
+                    }
+                }
+            }
+        }
+        div { class: "col-span-full", "Price per unit by soil:" }
+        for (soil_quality, price) in soil_prices {
+            if soil_quality == props.mix_state.soil_quality {
+                div {"{PriceItem::Soil(soil_quality).label()} (current)"}
+            } else {
+                div {"{PriceItem::Soil(soil_quality).label()}"}
+            }
+            div { class: "justify-self-end", "{price:.2}" }
+        }
+    }
+}
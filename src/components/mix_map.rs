@@ -1,6 +1,8 @@
 use crate::Effect;
 use dioxus::prelude::*;
 use web_sys::wasm_bindgen::JsCast;
+use crate::mixer_map::MixerMap;
+use crate::mixing::{disagreements, Geometric, ReactionTable};
 use crate::sellable::Sellable;
 
 const SCALE: f64 = 100.;
@@ -90,76 +92,50 @@ pub fn MixMap(props: ComponentProps) -> Element {
         let center = (width / 2., height / 2.);
         context.clear_rect(0., 0., width, height);
         draw_axis(&context, width, height);
-        let all_effects = [
-            Effect::AntiGravity,
-            Effect::Athletic,
-            Effect::Balding,
-            Effect::BrightEyed,
-            Effect::Calming,
-            Effect::CalorieDense,
-            Effect::Cyclopean,
-            Effect::Disorienting,
-            Effect::Electrifying,
-            Effect::Energizing,
-            Effect::Euphoric,
-            Effect::Explosive,
-            Effect::Focused,
-            Effect::Foggy,
-            Effect::Gingeritis,
-            Effect::Glowing,
-            Effect::Jennerising,
-            Effect::Laxative,
-            Effect::Lethal,
-            Effect::LongFaced,
-            Effect::Munchies,
-            Effect::Paranoia,
-            Effect::Refreshing,
-            Effect::Schizophrenic,
-            Effect::Sedating,
-            Effect::SeizureInducing,
-            Effect::Shrinking,
-            Effect::Slippery,
-            Effect::Smelly,
-            Effect::Sneaky,
-            Effect::Spicy,
-            Effect::ThoughtProvoking,
-            Effect::Toxic,
-            Effect::TropicThunder,
-            Effect::Zombifying,
-        ]; 
-        for effect in all_effects {
-            let direction = effect.direction();
-            let magnitude = effect.magnitude();
+        let map = MixerMap::for_product(previous_working_product.base);
+        for zone in map.zones.iter() {
             let circle_center = (
-                center.0 + direction.0 * magnitude * SCALE,
-                center.1 + direction.1 * magnitude * SCALE,
+                center.0 + zone.position.0 * SCALE,
+                center.1 + zone.position.1 * SCALE,
             );
-            draw_circle(&context, circle_center, 0.5 * SCALE, &effect.color());
+            draw_circle(&context, circle_center, zone.radius * SCALE, &zone.effect.color());
         }
         if let Some(added_effect) = *added_effect {
             previous_working_product.effects.iter().for_each(|effect| {
-                let direction = effect.direction();
-                let magnitude = effect.magnitude();
+                let position = map.zone(*effect).position;
                 let circle_center = (
-                    center.0 + direction.0 * magnitude * SCALE,
-                    center.1 + direction.1 * magnitude * SCALE,
+                    center.0 + position.0 * SCALE,
+                    center.1 + position.1 * SCALE,
                 );
                 draw_vector(&context, circle_center, added_effect.magnitude() * SCALE, added_effect.direction(), &effect.color());
             });
         }
-        for effect in all_effects {
-            let direction = effect.direction();
-            let magnitude = effect.magnitude();
+        for zone in map.zones.iter() {
             let circle_center = (
-                center.0 + direction.0 * magnitude * SCALE,
-                center.1 + direction.1 * magnitude * SCALE,
+                center.0 + zone.position.0 * SCALE,
+                center.1 + zone.position.1 * SCALE,
             );
             context.set_text_align("center");
             context.set_text_baseline("middle");
-            context.fill_text(format!("{effect:?}").as_str(), circle_center.0, circle_center.1).unwrap();
+            context.fill_text(format!("{:?}", zone.effect).as_str(), circle_center.0, circle_center.1).unwrap();
         }
     });
 
+    // pairs from the last mix where the geometric engine would have reacted differently
+    let disagreements = match *props.added_effect.read() {
+        Some(added_effect) => {
+            let previous_working_product = props.previous_working_product.read();
+            disagreements(previous_working_product.base, &ReactionTable, &Geometric)
+                .into_iter()
+                .filter(|d| {
+                    d.ingredient.effect() == added_effect
+                        && previous_working_product.effects.contains(&d.effect)
+                })
+                .collect()
+        }
+        None => vec![],
+    };
+
     rsx! {
         div {
             class: "flex flex-col justify-center col-span-full",
@@ -170,6 +146,12 @@ pub fn MixMap(props: ComponentProps) -> Element {
                 class: "w-auto",
                 style: "border: 1px solid #888;"
             }
+            if !MixerMap::is_measured(props.previous_working_product.read().base) {
+                div { "{props.previous_working_product.read().base:?} zones are not measured yet, showing the marijuana map" }
+            }
+            for d in disagreements {
+                div { "Geometric engine disagrees: {d.effect:?} + {d.ingredient:?} gives {d.right:?}, table gives {d.left:?}" }
+            }
         }
     }
 }
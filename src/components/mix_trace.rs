@@ -0,0 +1,41 @@
+use crate::sellable::{Ingredient, NewEffect, Sellable};
+use dioxus::prelude::*;
+
+#[derive(PartialEq, Clone, Props)]
+pub struct ComponentProps {
+    pub previous_working_product: Sellable,
+    pub ingredient: Ingredient,
+}
+
+#[component]
+pub fn MixTrace(props: ComponentProps) -> Element {
+    let (_, step) = props
+        .previous_working_product
+        .add_ingredient_traced(props.ingredient);
+    let new_effect = props.ingredient.effect();
+    rsx! {
+        div { class: "col-span-full", "Last mix: {props.ingredient:?}" }
+        if step.is_noop() {
+            div { class: "col-span-full border", "Warning: {props.ingredient:?} did nothing" }
+        }
+        for (old, new) in step.fired.iter() {
+            div { "{old:?} -> {new:?}" }
+            div { class: "justify-self-end", "reacted" }
+        }
+        for (old, new) in step.blocked.iter() {
+            div { "{old:?} -> {new:?}" }
+            div { class: "justify-self-end", "blocked, already has {new:?}" }
+        }
+        div { "{new_effect:?}" }
+        div {
+            class: "justify-self-end",
+            match step.new_effect {
+                NewEffect::Added => "added",
+                NewEffect::AlreadyPresent => "already present",
+                NewEffect::Capped => "not added, 8 effect limit",
+            }
+        }
+        div { "Price change" }
+        div { class: "justify-self-end", "{step.price_delta:+.0}" }
+    }
+}
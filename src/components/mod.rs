@@ -4,6 +4,9 @@ pub use button::*;
 mod icon_button;
 pub use icon_button::*;
 
+mod depth_input;
+pub use depth_input::*;
+
 mod add_ingredients;
 pub use add_ingredients::*;
 
@@ -46,3 +49,36 @@ pub use causes::*;
 mod mix_map;
 pub use mix_map::*;
 
+
+mod mix_trace;
+pub use mix_trace::*;
+
+mod optimize;
+pub use optimize::*;
+
+mod find_recipe;
+pub use find_recipe::*;
+
+mod next_ingredient;
+pub use next_ingredient::*;
+
+mod pareto_chart;
+pub use pareto_chart::*;
+
+mod export_graph;
+pub use export_graph::*;
+
+mod price_settings;
+pub use price_settings::*;
+
+mod grow_plan_options;
+pub use grow_plan_options::*;
+
+mod profit;
+pub use profit::*;
+
+mod product_quality;
+pub use product_quality::*;
+
+mod production_steps;
+pub use production_steps::*;
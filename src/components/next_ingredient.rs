@@ -0,0 +1,69 @@
+use crate::components::Button;
+use crate::sellable::{MixState, Sellable};
+use dioxus::prelude::*;
+
+#[derive(Clone, Copy, PartialEq)]
+enum SortBy {
+    Ingredient,
+    SellPrice,
+    Price,
+    Profit,
+}
+
+#[derive(Clone, PartialEq, Props)]
+pub struct ComponentProps {
+    pub working_product: Sellable,
+    pub mix_state: MixState,
+    /// whether clicking an ingredient that changes nothing still pays for it
+    pub keep_wasted: bool,
+}
+
+#[component]
+pub fn NextIngredient(props: ComponentProps) -> Element {
+    let mut sort_by = use_signal(|| SortBy::Profit);
+    let mut rows = props
+        .working_product
+        .lookahead(props.mix_state.clone(), props.keep_wasted);
+    match sort_by() {
+        SortBy::Ingredient => {}
+        SortBy::SellPrice => rows.sort_by_key(|row| std::cmp::Reverse(row.sell_price)),
+        SortBy::Price => rows.sort_by_key(|row| row.price),
+        SortBy::Profit => rows.sort_by_key(|row| std::cmp::Reverse(row.profit_delta)),
+    }
+    rsx! {
+        div {
+            class: "col-span-full grid gap-2 items-center",
+            style: "grid-template-columns: auto 1fr auto auto auto",
+            Button {
+                onclick: move |_| sort_by.set(SortBy::Ingredient),
+                active: sort_by() == SortBy::Ingredient,
+                "Next"
+            }
+            div { "Effects" }
+            Button {
+                onclick: move |_| sort_by.set(SortBy::SellPrice),
+                active: sort_by() == SortBy::SellPrice,
+                "Sell"
+            }
+            Button {
+                onclick: move |_| sort_by.set(SortBy::Price),
+                active: sort_by() == SortBy::Price,
+                "Cost"
+            }
+            Button {
+                onclick: move |_| sort_by.set(SortBy::Profit),
+                active: sort_by() == SortBy::Profit,
+                "Profit"
+            }
+            for row in rows {
+                div { "{row.ingredient:?}" }
+                div {
+                    {row.effects.iter().map(|effect| format!("{effect:?}")).collect::<Vec<_>>().join(", ")}
+                }
+                div { class: "justify-self-end", "{row.sell_price:.0}" }
+                div { class: "justify-self-end", "{row.price:.2}" }
+                div { class: "justify-self-end", "{row.profit_delta:+.2}" }
+            }
+        }
+    }
+}
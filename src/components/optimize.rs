@@ -0,0 +1,78 @@
+use crate::components::{Button, DepthInput};
+use crate::optimizer::{optimize, MAX_DEPTH};
+use crate::search::use_search;
+use crate::sellable::{MixState, Product, Sellable};
+use crate::transitions::TransitionCache;
+use dioxus::prelude::*;
+
+#[derive(PartialEq, Clone, Props)]
+pub struct ComponentProps {
+    pub product: Product,
+    pub mix_state: MixState,
+    pub set_working_product: EventHandler<Sellable>,
+}
+
+#[component]
+pub fn Optimize(props: ComponentProps) -> Element {
+    let mut depth = use_signal(|| 3);
+    let mut top_n = use_signal(|| 5);
+    let mut search = use_search::<Vec<Sellable>>();
+    let mix_state = props.mix_state.clone();
+    // the cache fills as the search runs, so reread it on every progress update
+    let stats = use_memo(move || {
+        (search.progress)();
+        TransitionCache::shared().stats()
+    });
+    rsx! {
+        div { class: "col-span-full flex justify-between gap-2",
+            "Optimize"
+            DepthInput { depth: depth(), max: MAX_DEPTH, set_depth: move |value| depth.set(value) }
+            label {
+                class: "flex gap-2 whitespace-nowrap items-center",
+                "Top",
+                input {
+                    r#type: "number",
+                    min: "1",
+                    value: "{top_n}",
+                    oninput: move |event| {
+                        if let Ok(value) = event.value().parse::<usize>() {
+                            top_n.set(value.max(1));
+                        }
+                    },
+                }
+            }
+        }
+        Button {
+            onclick: move |_| {
+                if search.is_running() {
+                    search.cancel();
+                } else {
+                    search.start(optimize(props.product, mix_state.clone(), depth(), top_n()));
+                }
+            },
+            if let Some(progress) = (search.progress)() {
+                "Cancel ({progress * 100.:.0}%)"
+            } else {
+                "Optimize"
+            }
+        }
+        {search.results.read().iter().flatten().map(|recipe| {
+            let recipe_clone = recipe.clone();
+            let profit = recipe.profit(props.mix_state.clone());
+            rsx! {
+                Button {
+                    key: "{recipe.key()}",
+                    onclick: move |_| props.set_working_product.call(recipe_clone.clone()),
+                    div { "{recipe.name}" }
+                    div { "{profit:.2} profit" }
+                }
+            }
+        })}
+        if search.results.read().is_some() {
+            div {
+                class: "col-span-full",
+                "Transition cache: {stats().entries} entries, {stats().hit_rate() * 100.:.0}% hits"
+            }
+        }
+    }
+}
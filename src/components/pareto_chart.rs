@@ -0,0 +1,84 @@
+use crate::components::{Button, DepthInput};
+use crate::optimizer::MAX_DEPTH;
+use crate::pareto::{pareto_frontier, ParetoPoint};
+use crate::search::use_search;
+use crate::sellable::{MixState, Product, Sellable};
+use dioxus::prelude::*;
+
+const WIDTH: f32 = 400.;
+const HEIGHT: f32 = 300.;
+const MARGIN: f32 = 30.;
+
+#[derive(PartialEq, Clone, Props)]
+pub struct ComponentProps {
+    pub product: Product,
+    pub mix_state: MixState,
+    pub set_working_product: EventHandler<Sellable>,
+}
+
+#[component]
+pub fn ParetoChart(props: ComponentProps) -> Element {
+    let mut depth = use_signal(|| 3);
+    let mut search = use_search::<Vec<ParetoPoint>>();
+    let points = search.results.read().clone().unwrap_or_default();
+    let mix_state = props.mix_state.clone();
+    let (min_profit, max_profit) = points
+        .iter()
+        .fold((f32::MAX, f32::MIN), |(min, max), point| {
+            (
+                min.min(point.profit.to_f32()),
+                max.max(point.profit.to_f32()),
+            )
+        });
+    let profit_range = (max_profit - min_profit).max(1.);
+    let max_steps = points.iter().map(|point| point.steps).max().unwrap_or(0);
+    rsx! {
+        div { class: "col-span-full flex justify-between gap-2",
+            "Profit vs Addictiveness"
+            DepthInput { depth: depth(), max: MAX_DEPTH, set_depth: move |value| depth.set(value) }
+        }
+        Button {
+            onclick: move |_| {
+                if search.is_running() {
+                    search.cancel();
+                } else {
+                    search.start(pareto_frontier(props.product, mix_state.clone(), depth()));
+                }
+            },
+            if let Some(progress) = (search.progress)() {
+                "Cancel ({progress * 100.:.0}%)"
+            } else {
+                "Analyze"
+            }
+        }
+        if !points.is_empty() {
+            div {
+                class: "col-span-full",
+                "x: profit ${min_profit:.0} to ${max_profit:.0}, y: addictiveness 0% to 100%, brighter is fewer steps"
+            }
+            svg {
+                class: "col-span-full",
+                view_box: "0 0 {WIDTH} {HEIGHT}",
+                style: "border: 1px solid #888;",
+                {points.iter().map(|point| {
+                    let recipe = point.recipe.clone();
+                    let x = MARGIN + (point.profit.to_f32() - min_profit) / profit_range * (WIDTH - 2. * MARGIN);
+                    let y = HEIGHT - MARGIN - point.addictiveness / 100. * (HEIGHT - 2. * MARGIN);
+                    let lightness = 80 - 50 * point.steps / max_steps.max(1);
+                    rsx! {
+                        circle {
+                            key: "{point.recipe.key()}",
+                            cx: "{x}",
+                            cy: "{y}",
+                            r: "6",
+                            fill: "hsl(200, 80%, {lightness}%)",
+                            style: "cursor: pointer;",
+                            onclick: move |_| props.set_working_product.call(recipe.clone()),
+                            title { "{point.recipe.name}: {point.profit:.2}, {point.addictiveness:.0}%, {point.steps} steps" }
+                        }
+                    }
+                })}
+            }
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use crate::components::Button;
+use crate::money::Money;
+use crate::price_book::{PriceBook, PriceItem};
+use dioxus::prelude::*;
+
+#[derive(PartialEq, Clone, Props)]
+pub struct ComponentProps {
+    pub prices: PriceBook,
+    pub set_prices: EventHandler<PriceBook>,
+}
+
+#[component]
+pub fn PriceSettings(props: ComponentProps) -> Element {
+    let mut editing = use_signal(|| false);
+    rsx! {
+        div { class: "col-span-full flex justify-between gap-2",
+            Button {
+                onclick: move |_| editing.set(!editing()),
+                active: editing(),
+                "Prices"
+            }
+            if editing() {
+                Button {
+                    onclick: move |_| props.set_prices.call(PriceBook::default()),
+                    "Reset"
+                }
+            }
+        }
+        if editing() {
+            for item in PriceItem::all() {
+                label {
+                    key: "{item:?}",
+                    class: "col-span-full flex justify-between gap-2 whitespace-nowrap items-center",
+                    "{item.label()}"
+                    input {
+                        r#type: "number",
+                        min: "0",
+                        step: "0.01",
+                        value: "{props.prices.get(item).to_f32()}",
+                        onchange: {
+                            let prices = props.prices.clone();
+                            move |event: Event<FormData>| {
+                                let price = event.value().parse::<f32>().ok();
+                                if let Some(price) = price.and_then(|price| Money::from_f32(price.max(0.))) {
+                                    let mut prices = prices.clone();
+                                    prices.set(item, price);
+                                    props.set_prices.call(prices);
+                                }
+                            }
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
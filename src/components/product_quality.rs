@@ -0,0 +1,12 @@
+use crate::components::price_per_unit::ComponentProps;
+use dioxus::prelude::*;
+
+#[component]
+pub fn QualityLevel(props: ComponentProps) -> Element {
+    rsx! {
+        div {
+            "Quality"
+        }
+        div { class: "justify-self-end", "{props.working_product.base.quality(&props.mix_state):?}" }
+    }
+}
@@ -0,0 +1,36 @@
+use crate::components::price_per_unit::ComponentProps;
+use crate::price_book::PriceItem;
+use crate::production::ProductionChain;
+use dioxus::prelude::*;
+
+#[component]
+pub fn ProductionSteps(props: ComponentProps) -> Element {
+    let Some(chain) = ProductionChain::of(props.working_product.base, &props.mix_state) else {
+        return rsx! {};
+    };
+    rsx! {
+        if let Some(leaves) = chain.leaves_per_batch {
+            div { class: "col-span-full", "Each batch of {chain.batch_units} from {leaves} leaves:" }
+        } else {
+            div { class: "col-span-full", "Each batch of {chain.batch_units}:" }
+        }
+        for step in chain.steps {
+            if step.inputs.is_empty() {
+                div { "{step.station.label()}" }
+            } else {
+                div {
+                    "{step.station.label()} ({inputs(&step.inputs)})"
+                }
+            }
+            div { class: "justify-self-end", "{step.hours}h" }
+        }
+    }
+}
+
+fn inputs(items: &[PriceItem]) -> String {
+    items
+        .iter()
+        .map(PriceItem::label)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
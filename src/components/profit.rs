@@ -0,0 +1,70 @@
+use crate::sellable::{MixState, Packaging, Sellable};
+use dioxus::prelude::*;
+
+#[derive(PartialEq, Clone, Props)]
+pub struct ComponentProps {
+    pub working_product: Sellable,
+    pub mix_state: MixState,
+    pub toggle_packaging: EventHandler<Packaging>,
+}
+
+#[component]
+pub fn Profit(props: ComponentProps) -> Element {
+    let profit = props.working_product.profit(props.mix_state.clone());
+    let margin = props.working_product.margin(props.mix_state.clone());
+    let ingredient_return = props
+        .working_product
+        .ingredient_return(props.mix_state.clone());
+    let hours = props.working_product.batch_hours(props.mix_state.clone());
+    let per_hour = props
+        .working_product
+        .profit_per_hour(props.mix_state.clone());
+    let best = props
+        .working_product
+        .best_packaging(props.mix_state.clone());
+    rsx! {
+        div {
+            class: "col-span-full",
+            "Profit:"
+        }
+        div {
+            "Unit"
+        }
+        div { class: "justify-self-end", "{profit:.2}" }
+        for packaging in Packaging::ALL {
+            label {
+                class: "flex gap-2 whitespace-nowrap items-center",
+                if packaging == best {
+                    "{packaging:?} (best)"
+                } else {
+                    "{packaging:?}"
+                }
+                if let Some(rank) = packaging.unlock() {
+                    input {
+                        r#type: "checkbox",
+                        title: "Unlocked at {rank}",
+                        checked: "{packaging.is_unlocked(&props.mix_state)}",
+                        onchange: move |_| props.toggle_packaging.call(packaging),
+                    }
+                }
+            }
+            div { class: "justify-self-end", "{props.working_product.package_profit(packaging, props.mix_state.clone()):.2}" }
+        }
+        div {
+            "Per in-game hour ({hours:.1}h a batch)"
+        }
+        div { class: "justify-self-end", "{per_hour:.2}" }
+        div {
+            "Margin"
+        }
+        div { class: "justify-self-end", "{margin:.0}%" }
+        div {
+            "Return on ingredients"
+        }
+        if let Some(ingredient_return) = ingredient_return {
+            div { class: "justify-self-end", "{ingredient_return:.0}%" }
+        } else {
+            div { class: "justify-self-end", "-" }
+        }
+    }
+}
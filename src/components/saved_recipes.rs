@@ -17,13 +17,20 @@ pub fn SavedRecipes(props: ComponentProps) -> Element {
             div { class: "col-span-full", "Saved Recipes" }
             {props.saved_recipes.iter().map(|(key, recipe)| {
                 let recipe_clone = recipe.clone();
+                let effects = recipe
+                    .effects
+                    .iter()
+                    .map(|effect| format!("{effect:?}"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
                 rsx! {
                     Button {
                         key: "{key}",
                         onclick: move |_| {
                             props.set_working_product.call(recipe_clone.clone());
                         },
-                        "{recipe.name}"
+                        div { "{recipe.name}" }
+                        div { "{effects}" }
                     }
                 }
             })}
@@ -1,4 +1,4 @@
-use crate::sellable::Sellable;
+use crate::sellable::{Packaging, Sellable};
 use dioxus::prelude::*;
 
 #[derive(PartialEq, Clone, Props)]
@@ -13,17 +13,15 @@ pub fn SellPrices(props: ComponentProps) -> Element {
             class: "col-span-full",
             "Sell Price:"
         }
-        div {
-            "Baggie"
-        }
-        div { class: "justify-self-end", "${props.working_product.sell_price():.0}" }
-        div {
-            "Jar"
-        }
-        div { class: "justify-self-end", "${props.working_product.sell_price()*5.:.0}" }
-        div {
-            "Brick"
+        for packaging in Packaging::ALL {
+            div {
+                if let Some(rank) = packaging.unlock() {
+                    "{packaging:?} ({rank})"
+                } else {
+                    "{packaging:?}"
+                }
+            }
+            div { class: "justify-self-end", "{props.working_product.package_sell_price(packaging):.0}" }
         }
-        div { class: "justify-self-end", "${props.working_product.sell_price()*20.:.0}" }
     }
 }
@@ -47,5 +47,8 @@ pub fn YieldOptions(props: ComponentProps) -> Element {
                 "/{props.working_product.yield_amount(props.mix_state.clone())}"
             }
         }
+        if !props.working_product.base.is_harvest_measured() {
+            div { class: "col-span-full", "Coca leaf counts are placeholders until counted in game" }
+        }
     }
 }
@@ -0,0 +1,166 @@
+use crate::sellable::Effect;
+
+const MULTIPLIERS: [f32; Effect::ALL.len()] = {
+    let mut multipliers = [0.; Effect::ALL.len()];
+    let mut i = 0;
+    while i < Effect::ALL.len() {
+        multipliers[i] = Effect::ALL[i].multiplier();
+        i += 1;
+    }
+    multipliers
+};
+
+const ADDICTIVENESS: [f32; Effect::ALL.len()] = {
+    let mut addictiveness = [0.; Effect::ALL.len()];
+    let mut i = 0;
+    while i < Effect::ALL.len() {
+        addictiveness[i] = Effect::ALL[i].addictiveness();
+        i += 1;
+    }
+    addictiveness
+};
+
+/// A set of effects packed into the bits of a `u64`, one bit per [`Effect`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct EffectSet(u64);
+
+impl EffectSet {
+    const fn bit(effect: Effect) -> u64 {
+        1 << effect as u64
+    }
+
+    pub fn contains(&self, effect: &Effect) -> bool {
+        self.0 & EffectSet::bit(*effect) != 0
+    }
+
+    /// Returns whether the effect was not already in the set.
+    pub fn insert(&mut self, effect: Effect) -> bool {
+        let added = !self.contains(&effect);
+        self.0 |= EffectSet::bit(effect);
+        added
+    }
+
+    /// Returns whether the effect was in the set.
+    pub fn remove(&mut self, effect: Effect) -> bool {
+        let removed = self.contains(&effect);
+        self.0 &= !EffectSet::bit(effect);
+        removed
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn union(&self, other: EffectSet) -> EffectSet {
+        EffectSet(self.0 | other.0)
+    }
+
+    pub fn intersection(&self, other: EffectSet) -> EffectSet {
+        EffectSet(self.0 & other.0)
+    }
+
+    pub fn difference(&self, other: EffectSet) -> EffectSet {
+        EffectSet(self.0 & !other.0)
+    }
+
+    pub fn is_subset(&self, other: EffectSet) -> bool {
+        self.difference(other).is_empty()
+    }
+
+    /// Effects in declaration order.
+    pub fn iter(&self) -> impl Iterator<Item = Effect> {
+        let bits = self.0;
+        Effect::ALL
+            .into_iter()
+            .filter(move |effect| bits & EffectSet::bit(*effect) != 0)
+    }
+
+    /// Sum of [`Effect::multiplier`] over the set.
+    pub fn multiplier_sum(&self) -> f32 {
+        self.sum(&MULTIPLIERS)
+    }
+
+    /// Sum of [`Effect::addictiveness`] over the set.
+    pub fn addictiveness_sum(&self) -> f32 {
+        self.sum(&ADDICTIVENESS)
+    }
+
+    fn sum(&self, values: &[f32; Effect::ALL.len()]) -> f32 {
+        let mut bits = self.0;
+        let mut sum = 0.;
+        while bits != 0 {
+            sum += values[bits.trailing_zeros() as usize];
+            bits &= bits - 1;
+        }
+        sum
+    }
+}
+
+impl FromIterator<Effect> for EffectSet {
+    fn from_iter<T: IntoIterator<Item = Effect>>(iter: T) -> Self {
+        let mut set = EffectSet::default();
+        for effect in iter {
+            set.insert(effect);
+        }
+        set
+    }
+}
+
+impl<const N: usize> From<[Effect; N]> for EffectSet {
+    fn from(effects: [Effect; N]) -> Self {
+        effects.into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_operations() {
+        let mut set = EffectSet::from([Effect::Calming, Effect::Zombifying]);
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&Effect::Zombifying));
+        assert!(!set.insert(Effect::Calming));
+        assert!(set.insert(Effect::AntiGravity));
+        assert!(set.remove(Effect::Calming));
+        assert!(!set.remove(Effect::Calming));
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            vec![Effect::AntiGravity, Effect::Zombifying]
+        );
+
+        let other = EffectSet::from([Effect::AntiGravity, Effect::Toxic]);
+        assert_eq!(
+            set.union(other),
+            EffectSet::from([Effect::AntiGravity, Effect::Toxic, Effect::Zombifying])
+        );
+        assert_eq!(
+            set.intersection(other),
+            EffectSet::from([Effect::AntiGravity])
+        );
+        assert_eq!(set.difference(other), EffectSet::from([Effect::Zombifying]));
+        assert!(EffectSet::from([Effect::Toxic]).is_subset(other));
+        assert!(!set.is_subset(other));
+    }
+
+    #[test]
+    fn test_sums() {
+        let set = EffectSet::from(Effect::ALL);
+        assert_eq!(set.len(), Effect::ALL.len());
+        let multiplier_sum = Effect::ALL
+            .iter()
+            .map(|effect| effect.multiplier())
+            .sum::<f32>();
+        assert!((set.multiplier_sum() - multiplier_sum).abs() < 0.0001);
+        assert_eq!(EffectSet::default().addictiveness_sum(), 0.);
+        assert_eq!(
+            EffectSet::from([Effect::Spicy, Effect::Calming]).addictiveness_sum(),
+            Effect::Spicy.addictiveness()
+        );
+    }
+}
@@ -0,0 +1,161 @@
+use crate::effect_set::EffectSet;
+use crate::money::Money;
+use crate::optimizer::{Explorer, Goal, TopRecipes};
+use crate::price_book::PriceBook;
+use crate::search::{Collector, SearchJob};
+use crate::sellable::{Product, Sellable};
+
+/// What a recipe search turned up.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Found {
+    /// best first
+    pub recipes: Vec<Sellable>,
+    /// desired effects no explored recipe had, wanted or not
+    pub unreached: EffectSet,
+}
+
+/// Recipes whose effects include all of `desired` and none of `forbidden`,
+/// best first by `goal`.
+pub fn find_recipes(
+    product: Product,
+    desired: EffectSet,
+    forbidden: EffectSet,
+    goal: Goal,
+    prices: PriceBook,
+    max_depth: usize,
+    top_n: usize,
+) -> SearchJob<Found> {
+    SearchJob::new(
+        Explorer::new(product, max_depth, goal, prices.clone()),
+        Matches {
+            desired,
+            forbidden,
+            goal,
+            prices,
+            reached: EffectSet::default(),
+            top: TopRecipes::new(top_n),
+        },
+    )
+}
+
+struct Matches {
+    desired: EffectSet,
+    forbidden: EffectSet,
+    goal: Goal,
+    prices: PriceBook,
+    /// every effect any recipe so far had
+    reached: EffectSet,
+    top: TopRecipes<((usize, Money, usize), String)>,
+}
+
+impl Collector for Matches {
+    type Output = Found;
+
+    fn add(&mut self, recipe: &Sellable) {
+        let effects = recipe.effects.set();
+        self.reached = self.reached.union(effects);
+        if self.desired.is_subset(effects) && effects.intersection(self.forbidden).is_empty() {
+            let rank = (self.goal.rank(recipe, &self.prices), recipe.key());
+            self.top.offer(rank, recipe);
+        }
+    }
+
+    fn results(&self) -> Found {
+        Found {
+            recipes: self.top.recipes(),
+            unreached: self.desired.difference(self.reached),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sellable::{Effect, Ingredient};
+
+    #[test]
+    fn test_find_cheapest() {
+        let desired = EffectSet::from([Effect::AntiGravity]);
+        let found = find_recipes(
+            Product::Marijuana(Effect::Calming),
+            desired,
+            EffectSet::default(),
+            Goal::Cheapest,
+            PriceBook::default(),
+            3,
+            3,
+        )
+        .run()
+        .recipes;
+        assert_eq!(found[0].ingredients, vec![Ingredient::MouthWash]);
+        assert!(found
+            .iter()
+            .all(|recipe| recipe.effects.contains(&Effect::AntiGravity)));
+    }
+
+    #[test]
+    fn test_find_forbidden() {
+        let found = find_recipes(
+            Product::Meth,
+            EffectSet::from([Effect::ThoughtProvoking]),
+            EffectSet::from([Effect::Gingeritis, Effect::Energizing]),
+            Goal::Shortest,
+            PriceBook::default(),
+            3,
+            10,
+        )
+        .run()
+        .recipes;
+        assert_eq!(found[0].ingredients, vec![Ingredient::Addy]);
+        assert!(found.iter().all(|recipe| {
+            !recipe.effects.contains(&Effect::Gingeritis)
+                && !recipe.effects.contains(&Effect::Energizing)
+        }));
+    }
+
+    #[test]
+    fn test_find_shortest() {
+        let desired = EffectSet::from([Effect::Energizing, Effect::ThoughtProvoking]);
+        let found = find_recipes(
+            Product::Meth,
+            desired,
+            EffectSet::default(),
+            Goal::Shortest,
+            PriceBook::default(),
+            3,
+            1,
+        )
+        .run()
+        .recipes;
+        assert_eq!(found[0].ingredients.len(), 2);
+        assert!(desired.is_subset(found[0].effects.set()));
+        assert!(find_recipes(
+            Product::Meth,
+            desired,
+            desired,
+            Goal::Shortest,
+            PriceBook::default(),
+            3,
+            1
+        )
+        .run()
+        .recipes
+        .is_empty());
+    }
+
+    #[test]
+    fn test_find_unreached() {
+        let found = find_recipes(
+            Product::Meth,
+            EffectSet::from([Effect::Energizing, Effect::Zombifying]),
+            EffectSet::default(),
+            Goal::Shortest,
+            PriceBook::default(),
+            1,
+            1,
+        )
+        .run();
+        assert!(found.recipes.is_empty());
+        assert_eq!(found.unreached, EffectSet::from([Effect::Zombifying]));
+    }
+}
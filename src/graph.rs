@@ -0,0 +1,204 @@
+use crate::money::Money;
+use crate::price_book::PriceBook;
+use crate::sellable::{EffectList, Ingredient, Product, Sellable};
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Write;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Node {
+    pub effects: EffectList,
+    pub sell_price: Money,
+    /// ingredients needed to first reach this state
+    pub depth: usize,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Edge {
+    pub from: usize,
+    pub to: usize,
+    pub ingredient: Ingredient,
+    pub cost: Money,
+}
+
+/// Every state reachable from a base product, as an automaton over ingredients.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StateGraph {
+    pub product: Product,
+    pub nodes: Vec<Node>,
+    pub edges: Vec<Edge>,
+}
+
+impl StateGraph {
+    /// Breadth first walk of every ingredient up to `max_depth` deep.
+    ///
+    /// Recipes ending in the same effects share a node, effects listed in a
+    /// different order stay apart since they can react differently later.
+    /// Ingredients that change nothing get no edge.
+    pub fn build(product: Product, max_depth: usize, prices: &PriceBook) -> Self {
+        let start = Sellable::from_product(product);
+        let mut ids = HashMap::<EffectList, usize>::new();
+        let mut nodes = vec![];
+        let mut edges = vec![];
+        let mut queue = VecDeque::new();
+        ids.insert(start.effects.clone(), 0);
+        nodes.push(Node {
+            effects: start.effects.clone(),
+            sell_price: start.sell_price(),
+            depth: 0,
+        });
+        queue.push_back((start, 0));
+        while let Some((recipe, from)) = queue.pop_front() {
+            let depth = nodes[from].depth;
+            if depth == max_depth {
+                continue;
+            }
+            for ingredient in Ingredient::ALL {
+                let mixed = recipe.clone().add_ingredient(ingredient);
+                if mixed.ingredients.len() == recipe.ingredients.len() {
+                    continue;
+                }
+                let to = match ids.get(&mixed.effects) {
+                    Some(to) => *to,
+                    None => {
+                        let to = nodes.len();
+                        ids.insert(mixed.effects.clone(), to);
+                        nodes.push(Node {
+                            effects: mixed.effects.clone(),
+                            sell_price: mixed.sell_price(),
+                            depth: depth + 1,
+                        });
+                        queue.push_back((mixed, to));
+                        to
+                    }
+                };
+                edges.push(Edge {
+                    from,
+                    to,
+                    ingredient,
+                    cost: prices.ingredient(ingredient),
+                });
+            }
+        }
+        StateGraph {
+            product,
+            nodes,
+            edges,
+        }
+    }
+
+    /// Graphviz DOT, nodes labelled with their effects and sell price.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph mixes {\n");
+        for (id, node) in self.nodes.iter().enumerate() {
+            let effects = effect_names(&node.effects).join("\\n");
+            let label = if effects.is_empty() {
+                format!("{:?}", self.product)
+            } else {
+                effects
+            };
+            writeln!(dot, "  {id} [label=\"{label}\\n{:.0}\"];", node.sell_price).unwrap();
+        }
+        for edge in &self.edges {
+            writeln!(
+                dot,
+                "  {} -> {} [label=\"{:?} {:.2}\"];",
+                edge.from, edge.to, edge.ingredient, edge.cost
+            )
+            .unwrap();
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// JSON list of nodes, each with its outgoing edges.
+    pub fn to_json(&self) -> String {
+        let mut adjacency = vec![vec![]; self.nodes.len()];
+        for edge in &self.edges {
+            adjacency[edge.from].push(format!(
+                "{{\"to\":{},\"ingredient\":\"{:?}\",\"cost\":{}}}",
+                edge.to,
+                edge.ingredient,
+                edge.cost.to_f32()
+            ));
+        }
+        let nodes = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(id, node)| {
+                let effects = effect_names(&node.effects)
+                    .iter()
+                    .map(|effect| format!("\"{effect}\""))
+                    .collect::<Vec<_>>()
+                    .join(",");
+                format!(
+                    "{{\"id\":{id},\"effects\":[{effects}],\"sell_price\":{},\"depth\":{},\"edges\":[{}]}}",
+                    node.sell_price.to_f32(),
+                    node.depth,
+                    adjacency[id].join(",")
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",\n");
+        format!(
+            "{{\"product\":\"{:?}\",\"nodes\":[\n{nodes}\n]}}\n",
+            self.product
+        )
+    }
+}
+
+fn effect_names(effects: &EffectList) -> Vec<String> {
+    effects.iter().map(|effect| format!("{effect:?}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::price_book::PriceItem;
+    use crate::sellable::Effect;
+
+    #[test]
+    fn test_build() {
+        let graph = StateGraph::build(
+            Product::Marijuana(Effect::Calming),
+            2,
+            &PriceBook::default(),
+        );
+        assert_eq!(graph.nodes[0].effects, EffectList::from([Effect::Calming]));
+        assert!(graph.nodes.iter().all(|node| node.depth <= 2));
+        // every ingredient changes the bare product
+        assert_eq!(graph.edges.iter().filter(|edge| edge.from == 0).count(), 16);
+        let mut seen = std::collections::HashSet::new();
+        assert!(graph
+            .nodes
+            .iter()
+            .all(|node| seen.insert(node.effects.clone())));
+        for edge in &graph.edges {
+            let mut from = Sellable::from_product(graph.product);
+            from.effects = graph.nodes[edge.from].effects.clone();
+            assert_eq!(
+                from.add_ingredient(edge.ingredient).effects,
+                graph.nodes[edge.to].effects
+            );
+        }
+    }
+
+    #[test]
+    fn test_exports() {
+        let mut prices = PriceBook::default();
+        prices.set(
+            PriceItem::Ingredient(Ingredient::Banana),
+            Money::from_f32(2.25).unwrap(),
+        );
+        let graph = StateGraph::build(Product::Meth, 1, &prices);
+        let dot = graph.to_dot();
+        assert!(dot.starts_with("digraph mixes {\n  0 [label=\"Meth\\n$70\"];\n"));
+        assert!(dot.contains("  0 -> 1 [label=\"Cuke $2.00\"];\n"));
+        assert!(dot.contains("[label=\"Banana $2.25\"];\n"));
+        let json = graph.to_json();
+        assert!(json.starts_with("{\"product\":\"Meth\",\"nodes\":[\n{\"id\":0,\"effects\":[],\"sell_price\":70,\"depth\":0,\"edges\":[{\"to\":1,\"ingredient\":\"Cuke\",\"cost\":2},"));
+        assert!(json.contains(
+            "{\"id\":1,\"effects\":[\"Energizing\"],\"sell_price\":85,\"depth\":1,\"edges\":[]}"
+        ));
+    }
+}
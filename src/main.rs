@@ -1,8 +1,10 @@
 use crate::components::{Expenses, YieldOptions};
 use crate::sellable::{Effect, MixState, Product, Sellable};
 use components::{
-    AddIngredients, Addictiveness, BaseProducts, Causes, MixMap, PricePerUnit, PseudoOptions,
-    SaveProduct, SavedRecipes, SellPrices, SoilOptions, TotalPrice,
+    AddIngredients, Addictiveness, BaseProducts, Causes, ExportGraph, FindRecipe, GrowPlanOptions,
+    MixMap, MixTrace, NextIngredient, Optimize, ParetoChart, PricePerUnit, PriceSettings,
+    ProductionSteps, Profit, PseudoOptions, QualityLevel, SaveProduct, SavedRecipes, SellPrices,
+    SoilOptions, TotalPrice,
 };
 use dioxus::prelude::*;
 use std::collections::HashMap;
@@ -11,7 +13,20 @@ const FAVICON: Asset = asset!("/assets/favicon.ico");
 const TAILWIND_CSS: Asset = asset!("/assets/tailwind.css");
 
 mod components;
+mod effect_set;
+mod finder;
+mod graph;
+mod mixer_map;
+mod mixing;
+mod money;
+mod optimizer;
+mod pareto;
+mod price_book;
+mod production;
+mod reactions;
+mod search;
 mod sellable;
+mod transitions;
 
 fn main() {
     dioxus::launch(App);
@@ -26,6 +41,14 @@ fn App() -> Element {
     let mut saved_recipes = use_signal(HashMap::<String, Sellable>::new);
     let mut mix_state = use_signal(MixState::default);
     let mut added_effect = use_signal(|| None);
+    let mut added_ingredient = use_signal(|| None);
+    let mut keep_wasted = use_signal(|| false);
+    let mut load_recipe = move |recipe: Sellable| {
+        working_product.set(recipe);
+        previous_working_product.set(working_product());
+        added_effect.set(None);
+        added_ingredient.set(None);
+    };
     rsx! {
         document::Link { rel: "icon", href: FAVICON }
         document::Link { rel: "stylesheet", href: TAILWIND_CSS }
@@ -34,23 +57,55 @@ fn App() -> Element {
             style: "grid-template-columns: minmax(365px, 1fr) minmax(240px, 1fr) minmax(150px, 1fr)",
             div {
                 class: "grid grid-cols-3 gap-4 content-start",
-                BaseProducts { set_working_product: move |product| {
-                    working_product.set(Sellable::from_product(product));
-                    added_effect.set(None);
-                }}
-                AddIngredients { add_ingredient: move |ingredient| {
-                    previous_working_product.set(working_product());
-                    working_product.set(working_product().add_ingredient(ingredient));
-                    added_effect.set(Some(ingredient.effect()));
-                }}
-                SavedRecipes {
-                    set_working_product: move |recipe| {
-                        working_product.set(recipe);
-                        added_effect.set(None);
+                BaseProducts {
+                    product: working_product.read().base,
+                    set_working_product: move |product| {
+                        load_recipe(Sellable::from_product(product));
+                    },
+                }
+                AddIngredients {
+                    add_ingredient: move |ingredient| {
+                        previous_working_product.set(working_product());
+                        if keep_wasted() {
+                            working_product.set(working_product().add_ingredient_recorded(ingredient));
+                        } else {
+                            working_product.set(working_product().add_ingredient(ingredient));
+                        }
+                        added_effect.set(Some(ingredient.effect()));
+                        added_ingredient.set(Some(ingredient));
                     },
+                    keep_wasted: keep_wasted(),
+                    set_keep_wasted: move |value| keep_wasted.set(value),
+                }
+                NextIngredient {
+                    working_product: working_product(),
+                    mix_state: mix_state(),
+                    keep_wasted: keep_wasted(),
+                }
+                SavedRecipes {
+                    set_working_product: load_recipe,
                     working_product: working_product(),
                     saved_recipes: saved_recipes(),
                 }
+                Optimize {
+                    product: working_product.read().base,
+                    mix_state: mix_state(),
+                    set_working_product: load_recipe,
+                }
+                FindRecipe {
+                    product: working_product.read().base,
+                    prices: mix_state.read().prices.clone(),
+                    set_working_product: load_recipe,
+                }
+                ParetoChart {
+                    product: working_product.read().base,
+                    mix_state: mix_state(),
+                    set_working_product: load_recipe,
+                }
+                ExportGraph {
+                    product: working_product.read().base,
+                    prices: mix_state.read().prices.clone(),
+                }
             }
             div {
                 class: "grid grid-cols-2 gap-4 content-start",
@@ -78,6 +133,10 @@ fn App() -> Element {
                             mix_state: mix_state(),
                             set_pseudo_quality: move |quality| mix_state.write().pseudo_quality = quality,
                         }
+                        ProductionSteps {
+                            working_product: working_product(),
+                            mix_state: mix_state(),
+                        }
                     },
                     _ => rsx! {
                         YieldOptions {
@@ -103,6 +162,15 @@ fn App() -> Element {
                                 }
                             },
                         }
+                        GrowPlanOptions {
+                            mix_state: mix_state(),
+                            working_product: working_product(),
+                            set_grow_plan: move |grow_plan| mix_state.write().grow_plan = grow_plan,
+                        }
+                        ProductionSteps {
+                            working_product: working_product(),
+                            mix_state: mix_state(),
+                        }
                     }
                 }
                 div { class: "border col-span-full" }
@@ -112,15 +180,35 @@ fn App() -> Element {
                 }
                 div { class: "border col-span-full" }
                 TotalPrice { working_product: working_product(), mix_state: mix_state() }
+                div { class: "border col-span-full" }
+                Profit {
+                    working_product: working_product(),
+                    mix_state: mix_state(),
+                    toggle_packaging: move |packaging| {
+                        if !mix_state.write().unlocked_packaging.remove(&packaging) {
+                            mix_state.write().unlocked_packaging.insert(packaging);
+                        }
+                    },
+                }
+                div { class: "border col-span-full" }
+                PriceSettings {
+                    prices: mix_state.read().prices.clone(),
+                    set_prices: move |prices| mix_state.write().prices = prices,
+                }
             }
             div {
                 class: "grid grid-cols-2 gap-4 content-start",
                 div { class: "col-span-full border", "Warning: Column in progress, has inaccuracies"}
                 Addictiveness { working_product: working_product() }
+                QualityLevel { working_product: working_product(), mix_state: mix_state() }
                 if !working_product.read().effects.is_empty() {
                     div { class: "border col-span-full" }
                     Causes { working_product: working_product() }
                 }
+                if let Some(ingredient) = added_ingredient() {
+                    div { class: "border col-span-full" }
+                    MixTrace { previous_working_product: previous_working_product(), ingredient }
+                }
                 MixMap {
                     added_effect: added_effect,
                     previous_working_product: previous_working_product,
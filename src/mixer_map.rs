@@ -0,0 +1,135 @@
+use crate::reactions::{Reaction, ReactionIndex, REACTIONS};
+use crate::sellable::{Effect, Product};
+use std::sync::OnceLock;
+
+/// Radius of an effect's zone unless a map says otherwise.
+pub const ZONE_RADIUS: f64 = 0.5;
+
+/// Where an effect sits on a mixer map, and how far its zone reaches.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Zone {
+    pub effect: Effect,
+    pub position: (f64, f64),
+    pub radius: f64,
+}
+
+/// The mixer map of one drug type: the effect zones plus the reactions they produce.
+#[derive(Clone, Debug)]
+pub struct MixerMap {
+    pub zones: Vec<Zone>,
+    reactions: ReactionIndex,
+}
+
+impl MixerMap {
+    /// The map `product` is mixed on. Meth and cocaine have maps of their own
+    /// in game, but until their zones are measured they share marijuana's,
+    /// see [`MixerMap::is_measured`].
+    pub fn for_product(product: Product) -> &'static MixerMap {
+        static MARIJUANA: OnceLock<MixerMap> = OnceLock::new();
+        match product {
+            Product::Marijuana(_) | Product::Meth | Product::Cocaine => {
+                MARIJUANA.get_or_init(|| MixerMap::new(&[], REACTIONS))
+            }
+        }
+    }
+
+    /// Whether `product` is mixed on its own measured map rather than
+    /// standing in on marijuana's.
+    pub fn is_measured(product: Product) -> bool {
+        matches!(product, Product::Marijuana(_))
+    }
+
+    /// Builds a map from the default layout with `overrides` replacing the
+    /// zones of the effects they name.
+    pub fn new(overrides: &[Zone], reactions: &[Reaction]) -> Self {
+        let zones = Effect::ALL
+            .iter()
+            .map(|effect| {
+                overrides
+                    .iter()
+                    .find(|zone| zone.effect == *effect)
+                    .copied()
+                    .unwrap_or_else(|| {
+                        let direction = effect.direction();
+                        let magnitude = effect.magnitude();
+                        Zone {
+                            effect: *effect,
+                            position: (direction.0 * magnitude, direction.1 * magnitude),
+                            radius: ZONE_RADIUS,
+                        }
+                    })
+            })
+            .collect();
+        MixerMap {
+            zones,
+            reactions: ReactionIndex::new(reactions),
+        }
+    }
+
+    pub fn zone(&self, effect: Effect) -> &Zone {
+        self.zones
+            .iter()
+            .find(|zone| zone.effect == effect)
+            .expect("every effect has a zone")
+    }
+
+    /// The effect whose zone contains `point`, closest first when zones overlap.
+    pub fn effect_at(&self, point: (f64, f64)) -> Option<Effect> {
+        self.zones
+            .iter()
+            .map(|zone| {
+                let distance = (zone.position.0 - point.0).hypot(zone.position.1 - point.1);
+                (zone, distance)
+            })
+            .filter(|(zone, distance)| *distance <= zone.radius)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(zone, _)| zone.effect)
+    }
+
+    pub fn reactions_for(&self, incoming: Effect) -> &[Reaction] {
+        self.reactions.reactions_for(incoming)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_effect_at() {
+        for product in [
+            Product::Marijuana(Effect::Calming),
+            Product::Meth,
+            Product::Cocaine,
+        ] {
+            let map = MixerMap::for_product(product);
+            for effect in Effect::ALL {
+                assert_eq!(map.effect_at(map.zone(effect).position), Some(effect));
+            }
+            assert_eq!(map.effect_at((0., 0.)), None);
+        }
+    }
+
+    #[test]
+    fn test_unmeasured_maps() {
+        let marijuana = MixerMap::for_product(Product::Marijuana(Effect::Calming));
+        for product in [Product::Meth, Product::Cocaine] {
+            assert!(!MixerMap::is_measured(product));
+            assert!(std::ptr::eq(MixerMap::for_product(product), marijuana));
+        }
+        assert!(MixerMap::is_measured(Product::Marijuana(Effect::Sedating)));
+    }
+
+    #[test]
+    fn test_overrides() {
+        let zone = Zone {
+            effect: Effect::Calming,
+            position: (0., 0.),
+            radius: 0.2,
+        };
+        let map = MixerMap::new(&[zone], REACTIONS);
+        assert_eq!(map.zone(Effect::Calming), &zone);
+        assert_eq!(map.effect_at((0.1, 0.1)), Some(Effect::Calming));
+        assert_eq!(map.effect_at((0.2, 0.2)), None);
+    }
+}
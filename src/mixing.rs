@@ -0,0 +1,176 @@
+use crate::mixer_map::MixerMap;
+use crate::sellable::{Effect, EffectList, Ingredient, Product};
+
+/// Decides which existing effects get replaced when an ingredient is mixed in.
+pub trait MixEngine {
+    /// The `(existing, replacement)` pairs caused by mixing `incoming` into `effects`.
+    fn reactions(
+        &self,
+        map: &MixerMap,
+        effects: &EffectList,
+        incoming: Effect,
+    ) -> Vec<(Effect, Effect)>;
+}
+
+/// Looks reactions up in the map's reaction table.
+pub struct ReactionTable;
+
+impl MixEngine for ReactionTable {
+    fn reactions(
+        &self,
+        map: &MixerMap,
+        effects: &EffectList,
+        incoming: Effect,
+    ) -> Vec<(Effect, Effect)> {
+        let mut reaction_list = vec![];
+        // the game works through the effects in order, so each condition
+        // sees the replacements made before it
+        let mut mixed = effects.clone();
+        for effect in effects.iter() {
+            for reaction in map.reactions_for(incoming) {
+                if reaction.existing == *effect && reaction.applies(|effect| mixed.contains(effect))
+                {
+                    reaction_list.push((reaction.existing, reaction.result));
+                    if !mixed.contains(&reaction.result) {
+                        mixed.replace(reaction.existing, reaction.result);
+                    }
+                }
+            }
+        }
+        reaction_list
+    }
+}
+
+/// Moves each existing effect's point on the mixer map by the incoming effect's
+/// vector and replaces it with whichever effect's zone it lands in.
+pub struct Geometric;
+
+impl MixEngine for Geometric {
+    fn reactions(
+        &self,
+        map: &MixerMap,
+        effects: &EffectList,
+        incoming: Effect,
+    ) -> Vec<(Effect, Effect)> {
+        let direction = incoming.direction();
+        let magnitude = incoming.magnitude();
+        let mut reaction_list = vec![];
+        for effect in effects.iter() {
+            let position = map.zone(*effect).position;
+            let point = (
+                position.0 + direction.0 * magnitude,
+                position.1 + direction.1 * magnitude,
+            );
+            match map.effect_at(point) {
+                Some(replacement) if replacement != *effect => {
+                    reaction_list.push((*effect, replacement))
+                }
+                _ => {}
+            }
+        }
+        reaction_list
+    }
+}
+
+/// An `(effect, ingredient)` pair that two engines react differently to.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Disagreement {
+    pub effect: Effect,
+    pub ingredient: Ingredient,
+    pub left: Option<Effect>,
+    pub right: Option<Effect>,
+}
+
+/// Mixes every ingredient into every single effect on `product`'s map with
+/// both engines and reports each pair where the replacements differ.
+pub fn disagreements(
+    product: Product,
+    left: &impl MixEngine,
+    right: &impl MixEngine,
+) -> Vec<Disagreement> {
+    let map = MixerMap::for_product(product);
+    let mut disagreements = vec![];
+    for effect in Effect::ALL {
+        let effects = EffectList::from([effect]);
+        for ingredient in Ingredient::ALL {
+            let left = replacement(left, map, &effects, ingredient.effect());
+            let right = replacement(right, map, &effects, ingredient.effect());
+            if left != right {
+                disagreements.push(Disagreement {
+                    effect,
+                    ingredient,
+                    left,
+                    right,
+                });
+            }
+        }
+    }
+    disagreements
+}
+
+fn replacement(
+    engine: &impl MixEngine,
+    map: &MixerMap,
+    effects: &EffectList,
+    incoming: Effect,
+) -> Option<Effect> {
+    engine
+        .reactions(map, effects, incoming)
+        .first()
+        .map(|(_, replacement)| *replacement)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_geometric_matches_table() {
+        let map = MixerMap::for_product(Product::Marijuana(Effect::Calming));
+        let effects = EffectList::from([Effect::Calming]);
+        assert_eq!(
+            Geometric.reactions(map, &effects, Effect::Balding),
+            vec![(Effect::Calming, Effect::AntiGravity)]
+        );
+        assert_eq!(
+            ReactionTable.reactions(map, &effects, Effect::Balding),
+            vec![(Effect::Calming, Effect::AntiGravity)]
+        );
+    }
+
+    #[test]
+    fn test_conditions_see_earlier_replacements() {
+        let map = MixerMap::for_product(Product::Marijuana(Effect::Calming));
+        // OG Kush, Paracetamol, Cuke and Paracetamol ends in Paranoia in game:
+        // Munchies turns into Anti-Gravity before Energizing is checked
+        let effects = EffectList::from([Effect::Munchies, Effect::Energizing]);
+        assert_eq!(
+            ReactionTable.reactions(map, &effects, Effect::Sneaky),
+            vec![
+                (Effect::Munchies, Effect::AntiGravity),
+                (Effect::Energizing, Effect::Paranoia),
+            ]
+        );
+        let effects = EffectList::from([Effect::Energizing, Effect::Munchies]);
+        assert_eq!(
+            ReactionTable.reactions(map, &effects, Effect::Sneaky),
+            vec![(Effect::Munchies, Effect::AntiGravity)]
+        );
+    }
+
+    #[test]
+    fn test_disagreements() {
+        assert!(disagreements(Product::Meth, &ReactionTable, &ReactionTable).is_empty());
+        let report = disagreements(Product::Meth, &ReactionTable, &Geometric);
+        assert!(report.iter().all(|d| d.left != d.right));
+        assert!(report.contains(&Disagreement {
+            effect: Effect::Cyclopean,
+            ingredient: Ingredient::Banana,
+            left: Some(Effect::ThoughtProvoking),
+            right: Some(Effect::Energizing),
+        }));
+        assert!(!report
+            .iter()
+            .any(|d| d.effect == Effect::Calming && d.ingredient == Ingredient::MouthWash));
+    }
+}
@@ -0,0 +1,219 @@
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+/// Fractions of a dollar kept, enough that amortized costs add back up to the cent.
+const SCALE: i64 = 10_000;
+
+/// Largest amount [`Money::from_f32`] takes either way, far past any price in
+/// the game yet small enough that totals of many such prices still fit.
+const MAX_DOLLARS: f64 = 1e9;
+
+/// An amount of dollars, exact to a hundredth of a cent.
+///
+/// Arithmetic saturates rather than overflowing. Formats as `$12.34`, with
+/// the precision and `+` flags respected, so `{:.0}` shows whole dollars.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Money(i64);
+
+impl Money {
+    pub const ZERO: Money = Money(0);
+
+    pub const fn dollars(dollars: i64) -> Self {
+        Money(dollars * SCALE)
+    }
+
+    /// The nearest amount to `dollars`, if it is a number no further from
+    /// zero than a billion.
+    pub fn from_f32(dollars: f32) -> Option<Self> {
+        let dollars = dollars as f64;
+        (dollars.abs() <= MAX_DOLLARS).then(|| Money((dollars * SCALE as f64).round() as i64))
+    }
+
+    pub fn to_f32(self) -> f32 {
+        (self.0 as f64 / SCALE as f64) as f32
+    }
+
+    /// Unity's `Mathf.RoundToInt`, which the game prices with: to the nearest
+    /// dollar with halves going to the even dollar.
+    pub fn round_game(self) -> Self {
+        let dollars = self.0.div_euclid(SCALE);
+        let rest = self.0.rem_euclid(SCALE);
+        let rounded = match (rest * 2).cmp(&SCALE) {
+            std::cmp::Ordering::Less => dollars,
+            std::cmp::Ordering::Greater => dollars + 1,
+            std::cmp::Ordering::Equal => dollars + dollars.rem_euclid(2),
+        };
+        Money::dollars(rounded)
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision().unwrap_or(2).min(4);
+        let unit = SCALE as u64 / 10_u64.pow(precision as u32);
+        // to the nearest displayed digit, halves away from zero
+        let digits = (self.0.unsigned_abs() + unit / 2) / unit;
+        let sign = if self.0 < 0 && digits != 0 {
+            "-"
+        } else if f.sign_plus() {
+            "+"
+        } else {
+            ""
+        };
+        let whole = digits / 10_u64.pow(precision as u32);
+        if precision == 0 {
+            write!(f, "{sign}${whole}")
+        } else {
+            let fraction = digits % 10_u64.pow(precision as u32);
+            write!(f, "{sign}${whole}.{fraction:0precision$}")
+        }
+    }
+}
+
+impl Add for Money {
+    type Output = Money;
+
+    fn add(self, other: Money) -> Money {
+        Money(self.0.saturating_add(other.0))
+    }
+}
+
+impl AddAssign for Money {
+    fn add_assign(&mut self, other: Money) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Money {
+    type Output = Money;
+
+    fn sub(self, other: Money) -> Money {
+        Money(self.0.saturating_sub(other.0))
+    }
+}
+
+impl SubAssign for Money {
+    fn sub_assign(&mut self, other: Money) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Money {
+    type Output = Money;
+
+    fn neg(self) -> Money {
+        Money(self.0.saturating_neg())
+    }
+}
+
+impl Mul<i64> for Money {
+    type Output = Money;
+
+    fn mul(self, times: i64) -> Money {
+        Money(self.0.saturating_mul(times))
+    }
+}
+
+/// Splits the amount into `parts`, to the nearest hundredth of a cent.
+/// `parts` that are not positive, or not a number, give zero rather than a
+/// saturated amount.
+impl Div<f32> for Money {
+    type Output = Money;
+
+    fn div(self, parts: f32) -> Money {
+        if parts > 0. {
+            Money((self.0 as f64 / parts as f64).round() as i64)
+        } else {
+            Money::ZERO
+        }
+    }
+}
+
+impl Sum for Money {
+    fn sum<I: Iterator<Item = Money>>(iter: I) -> Money {
+        iter.fold(Money::ZERO, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Money> for Money {
+    fn sum<I: Iterator<Item = &'a Money>>(iter: I) -> Money {
+        iter.copied().sum()
+    }
+}
+
+/// Compares against a dollar amount, so tests can check prices against plain numbers.
+#[cfg(test)]
+impl PartialEq<f32> for Money {
+    fn eq(&self, other: &f32) -> bool {
+        Money::from_f32(*other) == Some(*self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_game() {
+        assert_eq!(
+            Money::from_f32(38.5).unwrap().round_game(),
+            Money::dollars(38)
+        );
+        assert_eq!(
+            Money::from_f32(39.5).unwrap().round_game(),
+            Money::dollars(40)
+        );
+        assert_eq!(
+            Money::from_f32(46.2).unwrap().round_game(),
+            Money::dollars(46)
+        );
+        assert_eq!(
+            Money::from_f32(46.51).unwrap().round_game(),
+            Money::dollars(47)
+        );
+        assert_eq!(
+            Money::from_f32(-2.5).unwrap().round_game(),
+            Money::dollars(-2)
+        );
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let soil = Money::dollars(60) / 36.;
+        assert_eq!(soil * 36, Money::from_f32(60.0012).unwrap());
+        assert_eq!(
+            [Money::dollars(2), Money::from_f32(0.25).unwrap()]
+                .iter()
+                .sum::<Money>(),
+            2.25
+        );
+        assert_eq!(Money::dollars(5) - Money::dollars(7), -Money::dollars(2));
+        assert_eq!(Money::dollars(5) / 0., Money::ZERO);
+        assert_eq!(Money::dollars(5) / -2., Money::ZERO);
+        assert_eq!(Money::dollars(5) / f32::NAN, Money::ZERO);
+        assert_eq!(Money::dollars(5) / f32::INFINITY, Money::ZERO);
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        assert_eq!(Money::from_f32(1e30), None);
+        assert_eq!(Money::from_f32(f32::INFINITY), None);
+        assert_eq!(Money::from_f32(f32::NAN), None);
+        assert_eq!(Money::from_f32(1e9), Some(Money::dollars(1_000_000_000)));
+        let most = Money(i64::MAX);
+        assert_eq!(most + Money::dollars(1), most);
+        assert_eq!([most, most].iter().sum::<Money>(), most);
+        assert_eq!(-most - Money::dollars(2), Money(i64::MIN));
+        assert_eq!(format!("{:.0}", Money(i64::MIN)), "-$922337203685478");
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Money::from_f32(1.875).unwrap()), "$1.88");
+        assert_eq!(format!("{:.0}", Money::from_f32(45.5).unwrap()), "$46");
+        assert_eq!(format!("{:+.0}", Money::dollars(16)), "+$16");
+        assert_eq!(format!("{:.2}", -Money::from_f32(0.5).unwrap()), "-$0.50");
+        assert_eq!(format!("{:.2}", -Money::from_f32(0.001).unwrap()), "$0.00");
+    }
+}
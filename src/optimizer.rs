@@ -0,0 +1,478 @@
+use crate::money::Money;
+use crate::price_book::PriceBook;
+use crate::search::{Collector, SearchJob};
+use crate::sellable::{EffectList, Ingredient, MixState, Product, Sellable};
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+/// Deepest search the UI offers, deeper ones take minutes and hold millions
+/// of recipes in memory.
+pub const MAX_DEPTH: usize = 5;
+
+/// Searches every ingredient sequence up to `max_depth` long for the `top_n`
+/// recipes with the highest profit per unit, best first.
+///
+/// Recipes ending in the same effects only keep the cheapest way there, and
+/// ingredients that change nothing are never added.
+pub fn optimize(
+    product: Product,
+    state: MixState,
+    max_depth: usize,
+    top_n: usize,
+) -> SearchJob<Vec<Sellable>> {
+    SearchJob::new(
+        Explorer::new(product, max_depth, Goal::Cheapest, state.prices.clone()),
+        MostProfitable {
+            state,
+            top: TopRecipes::new(top_n),
+        },
+    )
+}
+
+struct MostProfitable {
+    state: MixState,
+    top: TopRecipes<(Reverse<Money>, usize, String)>,
+}
+
+impl Collector for MostProfitable {
+    type Output = Vec<Sellable>;
+
+    fn add(&mut self, recipe: &Sellable) {
+        let rank = (
+            Reverse(recipe.profit(self.state.clone())),
+            recipe.ingredients.len(),
+            recipe.key(),
+        );
+        self.top.offer(rank, recipe);
+    }
+
+    fn results(&self) -> Vec<Sellable> {
+        self.top.recipes()
+    }
+}
+
+/// The `top_n` recipes ranked lowest, one per effect list, kept up to date
+/// as recipes come in so the whole search never needs sorting.
+pub struct TopRecipes<K> {
+    top_n: usize,
+    entries: Vec<(K, Sellable)>,
+}
+
+impl<K: Ord> TopRecipes<K> {
+    pub fn new(top_n: usize) -> Self {
+        TopRecipes {
+            top_n,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Takes `recipe` in place of any kept recipe with the same effects, as
+    /// a search only offers a better way to effects it has already reached.
+    pub fn offer(&mut self, rank: K, recipe: &Sellable) {
+        if let Some(index) = self
+            .entries
+            .iter()
+            .position(|(_, kept)| kept.effects == recipe.effects)
+        {
+            self.entries.remove(index);
+        }
+        let index = self.entries.partition_point(|(kept, _)| *kept < rank);
+        if index < self.top_n {
+            self.entries.insert(index, (rank, recipe.clone()));
+            self.entries.truncate(self.top_n);
+        }
+    }
+
+    /// Lowest rank first.
+    pub fn recipes(&self) -> Vec<Sellable> {
+        self.entries
+            .iter()
+            .map(|(_, recipe)| recipe.clone())
+            .collect()
+    }
+}
+
+/// Which recipe to keep when several reach the same effects.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Goal {
+    /// lowest ingredient cost, then fewest ingredients
+    Cheapest,
+    /// fewest ingredients, then lowest ingredient cost
+    Shortest,
+}
+
+impl Goal {
+    /// What the goal keeps lowest, compared in order.
+    pub fn rank(&self, recipe: &Sellable, prices: &PriceBook) -> (usize, Money, usize) {
+        let cost = ingredient_cost(recipe, prices);
+        let len = recipe.ingredients.len();
+        match self {
+            Goal::Cheapest => (0, cost, len),
+            Goal::Shortest => (len, cost, 0),
+        }
+    }
+
+    /// Ties go to the first recipe by [`Sellable::key`], so the winner never
+    /// depends on the order recipes were found in.
+    fn improves(&self, recipe: &Sellable, other: &Sellable, prices: &PriceBook) -> bool {
+        self.rank(recipe, prices)
+            .cmp(&self.rank(other, prices))
+            .then_with(|| recipe.key().cmp(&other.key()))
+            .is_lt()
+    }
+}
+
+/// Explores recipes grouped by ingredient count, starting with the bare product,
+/// a few recipes at a time so long searches can report progress and stop early.
+///
+/// Each layer holds the best recipe by `goal` for every effect list first
+/// reached, or reached better than before, with that many ingredients.
+pub struct Explorer {
+    goal: Goal,
+    prices: PriceBook,
+    max_depth: usize,
+    best: HashMap<EffectList, Sellable>,
+    layers: Vec<Vec<Sellable>>,
+    next: HashMap<EffectList, Sellable>,
+    /// how much of the last layer has been mixed into `next`
+    cursor: usize,
+}
+
+impl Explorer {
+    /// `prices` decide which recipe is cheapest.
+    pub fn new(product: Product, max_depth: usize, goal: Goal, prices: PriceBook) -> Self {
+        let start = Sellable::from_product(product);
+        Explorer {
+            goal,
+            prices,
+            max_depth,
+            best: HashMap::from([(start.effects.clone(), start.clone())]),
+            layers: vec![vec![start]],
+            next: HashMap::new(),
+            cursor: 0,
+        }
+    }
+
+    /// Mixes every ingredient into up to `budget` more recipes, returning
+    /// whether that completed a layer.
+    #[cfg(any(test, target_arch = "wasm32"))]
+    pub fn step(&mut self, budget: usize) -> bool {
+        if self.is_done() {
+            return false;
+        }
+        let layer = self.layers.last().unwrap();
+        let end = layer.len().min(self.cursor.saturating_add(budget));
+        let mut next = std::mem::take(&mut self.next);
+        self.expand_into(&layer[self.cursor..end], &mut next);
+        self.next = next;
+        self.cursor = end;
+        if self.cursor < layer.len() {
+            return false;
+        }
+        self.finish_layer();
+        true
+    }
+
+    /// Finishes the current layer on `threads` threads, which live for the
+    /// whole layer and take `chunk` recipes at a time, calling `report` with
+    /// the progress after each chunk.
+    ///
+    /// Returns whether the layer was finished; once `report` returns false
+    /// the threads stop at their next chunk and the partial layer is dropped.
+    /// Ties are broken the same way whichever thread finds a recipe, so this
+    /// explores exactly the same recipes as [`Explorer::step`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn step_layer(
+        &mut self,
+        threads: usize,
+        chunk: usize,
+        mut report: impl FnMut(f32) -> bool,
+    ) -> bool {
+        use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+        if self.is_done() {
+            return false;
+        }
+        let explorer = &*self;
+        let layer = &explorer.layers.last().unwrap()[explorer.cursor..];
+        let chunk = chunk.max(1);
+        let taken = AtomicUsize::new(0);
+        let stop = AtomicBool::new(false);
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let found = std::thread::scope(|scope| {
+            let workers = (0..threads.max(1))
+                .map(|_| {
+                    let sender = sender.clone();
+                    let (taken, stop) = (&taken, &stop);
+                    scope.spawn(move || {
+                        let mut found = HashMap::new();
+                        while !stop.load(Ordering::Relaxed) {
+                            let start = taken.fetch_add(chunk, Ordering::Relaxed);
+                            if start >= layer.len() {
+                                break;
+                            }
+                            let end = layer.len().min(start + chunk);
+                            explorer.expand_into(&layer[start..end], &mut found);
+                            let _ = sender.send(end - start);
+                        }
+                        found
+                    })
+                })
+                .collect::<Vec<_>>();
+            drop(sender);
+            let mut done = explorer.cursor;
+            for mixed in receiver {
+                done += mixed;
+                if !report(explorer.progress_at(done)) {
+                    stop.store(true, Ordering::Relaxed);
+                }
+            }
+            workers
+                .into_iter()
+                .map(|worker| worker.join().unwrap())
+                .collect::<Vec<_>>()
+        });
+        if stop.into_inner() {
+            return false;
+        }
+        let mut next = std::mem::take(&mut self.next);
+        for found in found {
+            self.merge(&mut next, found);
+        }
+        self.next = next;
+        self.finish_layer();
+        true
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn merge(
+        &self,
+        into: &mut HashMap<EffectList, Sellable>,
+        found: HashMap<EffectList, Sellable>,
+    ) {
+        for (effects, recipe) in found {
+            if into
+                .get(&effects)
+                .is_none_or(|seen| self.goal.improves(&recipe, seen, &self.prices))
+            {
+                into.insert(effects, recipe);
+            }
+        }
+    }
+
+    fn finish_layer(&mut self) {
+        let next = std::mem::take(&mut self.next);
+        for (effects, recipe) in next.iter() {
+            self.best.insert(effects.clone(), recipe.clone());
+        }
+        self.layers.push(next.into_values().collect());
+        self.cursor = 0;
+    }
+
+    /// Adds to `found` the best new recipe for each effect list one
+    /// ingredient away from `recipes`.
+    fn expand_into(&self, recipes: &[Sellable], found: &mut HashMap<EffectList, Sellable>) {
+        for recipe in recipes {
+            for ingredient in Ingredient::ALL {
+                let mixed = recipe.clone().add_ingredient(ingredient);
+                if mixed.ingredients.len() == recipe.ingredients.len() {
+                    continue;
+                }
+                let improves = |other: &Sellable| self.goal.improves(&mixed, other, &self.prices);
+                if self
+                    .best
+                    .get(&mixed.effects)
+                    .is_some_and(|seen| !improves(seen))
+                    || found
+                        .get(&mixed.effects)
+                        .is_some_and(|seen| !improves(seen))
+                {
+                    continue;
+                }
+                found.insert(mixed.effects.clone(), mixed);
+            }
+        }
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.layers.len() > self.max_depth
+    }
+
+    /// Roughly how far through the search this is, from 0 to 1.
+    pub fn progress(&self) -> f32 {
+        if self.is_done() {
+            return 1.;
+        }
+        self.progress_at(self.cursor)
+    }
+
+    /// [`Explorer::progress`] with `mixed` recipes of the last layer mixed.
+    fn progress_at(&self, mixed: usize) -> f32 {
+        let layer = mixed as f32 / self.layers.last().unwrap().len().max(1) as f32;
+        ((self.layers.len() - 1) as f32 + layer) / self.max_depth as f32
+    }
+
+    /// The best recipe by `goal` for every effect list in the layers finished so far.
+    #[cfg(test)]
+    pub fn best(&self) -> Vec<Sellable> {
+        self.best.values().cloned().collect()
+    }
+
+    /// The layers finished so far.
+    pub fn layers(&self) -> &[Vec<Sellable>] {
+        &self.layers
+    }
+}
+
+pub fn ingredient_cost(recipe: &Sellable, prices: &PriceBook) -> Money {
+    recipe
+        .ingredients
+        .iter()
+        .map(|ingredient| prices.ingredient(*ingredient))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sellable::Effect;
+
+    fn brute_force(product: Product, state: &MixState, depth: usize) -> Money {
+        let mut recipes = vec![Sellable::from_product(product)];
+        let mut best = recipes[0].profit(state.clone());
+        for _ in 0..depth {
+            recipes = recipes
+                .iter()
+                .flat_map(|recipe| {
+                    Ingredient::ALL.map(|ingredient| recipe.clone().add_ingredient(ingredient))
+                })
+                .collect();
+            for recipe in &recipes {
+                best = best.max(recipe.profit(state.clone()));
+            }
+        }
+        best
+    }
+
+    #[test]
+    fn test_optimize_matches_brute_force() {
+        let state = MixState::default();
+        for product in [Product::Marijuana(Effect::Calming), Product::Meth] {
+            let best = optimize(product, state.clone(), 3, 5).run();
+            assert_eq!(best.len(), 5);
+            assert_eq!(
+                best[0].profit(state.clone()),
+                brute_force(product, &state, 3)
+            );
+            assert!(best
+                .windows(2)
+                .all(|pair| pair[0].profit(state.clone()) >= pair[1].profit(state.clone())));
+        }
+    }
+
+    #[test]
+    fn test_explorer_steps() {
+        let product = Product::Meth;
+        let mut explorer = Explorer::new(product, 2, Goal::Shortest, PriceBook::default());
+        assert_eq!(explorer.progress(), 0.);
+        // the bare product is the whole first layer
+        assert!(explorer.step(1));
+        assert_eq!(explorer.progress(), 0.5);
+        assert!(!explorer.step(3));
+        assert!(explorer.progress() > 0.5);
+        while !explorer.step(3) {}
+        assert!(explorer.is_done());
+        assert_eq!(explorer.progress(), 1.);
+        assert!(!explorer.step(3));
+        // equally good recipes can win either way, so compare what was reached
+        let keys = |layers: &[Vec<Sellable>]| {
+            layers
+                .iter()
+                .map(|layer| {
+                    let mut keys = layer
+                        .iter()
+                        .map(|recipe| format!("{:?}", recipe.effects))
+                        .collect::<Vec<_>>();
+                    keys.sort();
+                    keys
+                })
+                .collect::<Vec<_>>()
+        };
+        let mut whole = Explorer::new(product, 2, Goal::Shortest, PriceBook::default());
+        while !whole.is_done() {
+            whole.step(usize::MAX);
+        }
+        assert_eq!(keys(explorer.layers()), keys(whole.layers()));
+    }
+
+    #[test]
+    fn test_step_layer_matches_step() {
+        for goal in [Goal::Cheapest, Goal::Shortest] {
+            let product = Product::Marijuana(Effect::Calming);
+            let mut single = Explorer::new(product, 3, goal, PriceBook::default());
+            let mut parallel = Explorer::new(product, 3, goal, PriceBook::default());
+            while !single.is_done() {
+                single.step(usize::MAX);
+            }
+            single.step(5);
+            parallel.step(5);
+            let mut reports = 0;
+            while !parallel.is_done() {
+                assert!(parallel.step_layer(4, 37, |_| {
+                    reports += 1;
+                    true
+                }));
+            }
+            assert!(reports > 3);
+            let keys = |explorer: &Explorer| {
+                let mut keys = explorer
+                    .best()
+                    .iter()
+                    .map(Sellable::key)
+                    .collect::<Vec<_>>();
+                keys.sort();
+                keys
+            };
+            assert_eq!(keys(&single), keys(&parallel));
+        }
+    }
+
+    #[test]
+    fn test_step_layer_cancel() {
+        let product = Product::Marijuana(Effect::Calming);
+        let mut explorer = Explorer::new(product, 3, Goal::Cheapest, PriceBook::default());
+        explorer.step(usize::MAX);
+        let mut progress = Vec::new();
+        assert!(!explorer.step_layer(2, 1, |update| {
+            progress.push(update);
+            false
+        }));
+        assert_eq!(explorer.layers().len(), 2);
+        assert!(explorer.next.is_empty());
+        assert!(progress[0] > 1. / 3.);
+        assert!(explorer.step_layer(2, 1, |_| true));
+        assert_eq!(explorer.layers().len(), 3);
+    }
+
+    #[test]
+    fn test_top_recipes() {
+        let meth = Sellable::from_product(Product::Meth);
+        let cuke = meth.add_ingredient_recorded(Ingredient::Cuke);
+        let banana = meth.add_ingredient_recorded(Ingredient::Banana);
+        let mut top = TopRecipes::new(2);
+        top.offer(3, &meth);
+        top.offer(1, &cuke);
+        top.offer(2, &banana);
+        assert_eq!(top.recipes(), vec![cuke.clone(), banana.clone()]);
+        // a better way to the same effects replaces the old one
+        let cheaper = cuke.with_name("cheaper".to_string());
+        top.offer(0, &cheaper);
+        assert_eq!(top.recipes(), vec![cheaper, banana]);
+    }
+
+    #[test]
+    fn test_optimize_depth_zero() {
+        let best = optimize(Product::Cocaine, MixState::default(), 0, 3).run();
+        assert_eq!(best, vec![Sellable::from_product(Product::Cocaine)]);
+    }
+}
@@ -0,0 +1,115 @@
+use crate::money::Money;
+use crate::optimizer::{Explorer, Goal};
+use crate::search::{Collector, SearchJob};
+use crate::sellable::{MixState, Product, Sellable};
+use std::collections::HashMap;
+
+/// A recipe scored on everything the frontier trades off.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParetoPoint {
+    pub recipe: Sellable,
+    pub profit: Money,
+    pub addictiveness: f32,
+    pub steps: usize,
+}
+
+impl ParetoPoint {
+    fn new(recipe: Sellable, state: &MixState) -> Self {
+        ParetoPoint {
+            profit: recipe.profit(state.clone()),
+            addictiveness: recipe.addictiveness(),
+            steps: recipe.ingredients.len(),
+            recipe,
+        }
+    }
+
+    /// At least as good on every axis and better on one.
+    fn dominates(&self, other: &ParetoPoint) -> bool {
+        self.profit >= other.profit
+            && self.addictiveness >= other.addictiveness
+            && self.steps <= other.steps
+            && (self.profit > other.profit
+                || self.addictiveness > other.addictiveness
+                || self.steps < other.steps)
+    }
+}
+
+/// Recipes up to `max_depth` ingredients that no other recipe beats on profit
+/// per unit, addictiveness and ingredient count at once, most profitable first.
+pub fn pareto_frontier(
+    product: Product,
+    state: MixState,
+    max_depth: usize,
+) -> SearchJob<Vec<ParetoPoint>> {
+    SearchJob::new(
+        Explorer::new(product, max_depth, Goal::Cheapest, state.prices.clone()),
+        Frontier {
+            state,
+            buckets: HashMap::new(),
+        },
+    )
+}
+
+/// Addictiveness is a whole percentage, so the most profitable recipe for
+/// each addictiveness and length leaves few enough points to compare pairwise.
+struct Frontier {
+    state: MixState,
+    buckets: HashMap<(u32, usize), ParetoPoint>,
+}
+
+impl Collector for Frontier {
+    type Output = Vec<ParetoPoint>;
+
+    fn add(&mut self, recipe: &Sellable) {
+        let profit = recipe.profit(self.state.clone());
+        let bucket = (recipe.addictiveness() as u32, recipe.ingredients.len());
+        if self.buckets.get(&bucket).is_none_or(|best| {
+            profit > best.profit || (profit == best.profit && recipe.key() < best.recipe.key())
+        }) {
+            self.buckets
+                .insert(bucket, ParetoPoint::new(recipe.clone(), &self.state));
+        }
+    }
+
+    fn results(&self) -> Vec<ParetoPoint> {
+        let mut frontier = self
+            .buckets
+            .values()
+            .filter(|point| !self.buckets.values().any(|other| other.dominates(point)))
+            .cloned()
+            .collect::<Vec<_>>();
+        frontier.sort_by(|a, b| {
+            b.profit
+                .cmp(&a.profit)
+                .then(a.steps.cmp(&b.steps))
+                .then(b.addictiveness.total_cmp(&a.addictiveness))
+        });
+        frontier
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optimizer::optimize;
+    use crate::sellable::Effect;
+
+    #[test]
+    fn test_pareto_frontier() {
+        let state = MixState::default();
+        let product = Product::Marijuana(Effect::Calming);
+        let frontier = pareto_frontier(product, state.clone(), 3).run();
+        for point in &frontier {
+            assert!(!frontier.iter().any(|other| other.dominates(point)));
+        }
+        // the bare product is the only zero step recipe
+        assert!(frontier.iter().any(|point| point.steps == 0));
+        let best = &optimize(product, state.clone(), 3, 1).run()[0];
+        assert_eq!(frontier[0].profit, best.profit(state.clone()));
+        let most_addictive = frontier
+            .iter()
+            .map(|point| point.addictiveness)
+            .fold(0., f32::max);
+        assert_eq!(most_addictive, 100.);
+    }
+}
@@ -0,0 +1,176 @@
+use crate::money::Money;
+use crate::sellable::{Effect, Ingredient, OneTimeIngredient, Packaging, Quality};
+use std::collections::HashMap;
+
+/// Anything with a purchase price.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum PriceItem {
+    Ingredient(Ingredient),
+    /// marijuana seed by the strain's starting effect
+    Seed(Effect),
+    CocaSeed,
+    Pseudo(Quality),
+    Acid,
+    RedPhosphorus,
+    Soil(Quality),
+    Additive(OneTimeIngredient),
+    /// the container a package is sold in
+    Packaging(Packaging),
+}
+
+impl PriceItem {
+    /// Every item, in the order the settings list them.
+    pub fn all() -> Vec<PriceItem> {
+        let mut items = vec![
+            PriceItem::Seed(Effect::Calming),
+            PriceItem::Seed(Effect::Refreshing),
+            PriceItem::Seed(Effect::Energizing),
+            PriceItem::Seed(Effect::Sedating),
+            PriceItem::CocaSeed,
+            PriceItem::Pseudo(Quality::Low),
+            PriceItem::Pseudo(Quality::Medium),
+            PriceItem::Pseudo(Quality::High),
+            PriceItem::Acid,
+            PriceItem::RedPhosphorus,
+            PriceItem::Soil(Quality::Low),
+            PriceItem::Soil(Quality::Medium),
+            PriceItem::Soil(Quality::High),
+            PriceItem::Additive(OneTimeIngredient::PGR),
+            PriceItem::Additive(OneTimeIngredient::Fertilizer),
+            PriceItem::Additive(OneTimeIngredient::SpeedGrow),
+        ];
+        items.extend(Packaging::ALL.map(PriceItem::Packaging));
+        items.extend(Ingredient::ALL.map(PriceItem::Ingredient));
+        items
+    }
+
+    /// What the item is called in game.
+    pub fn label(&self) -> String {
+        match self {
+            PriceItem::Ingredient(ingredient) => format!("{ingredient:?}"),
+            PriceItem::Seed(Effect::Calming) => "OG Kush Seed".to_string(),
+            PriceItem::Seed(Effect::Refreshing) => "Sour Diesel Seed".to_string(),
+            PriceItem::Seed(Effect::Energizing) => "Green Crack Seed".to_string(),
+            PriceItem::Seed(Effect::Sedating) => "Granddaddy Purple Seed".to_string(),
+            PriceItem::Seed(effect) => format!("{effect:?} Seed"),
+            PriceItem::CocaSeed => "Coca Seed".to_string(),
+            PriceItem::Pseudo(Quality::Low) => "Low Quality Pseudo".to_string(),
+            PriceItem::Pseudo(Quality::Medium) => "Pseudo".to_string(),
+            PriceItem::Pseudo(Quality::High) => "High Quality Pseudo".to_string(),
+            PriceItem::Acid => "Acid".to_string(),
+            PriceItem::RedPhosphorus => "Red Phosphorus".to_string(),
+            PriceItem::Soil(Quality::Low) => "Soil".to_string(),
+            PriceItem::Soil(Quality::Medium) => "Long-Life Soil".to_string(),
+            PriceItem::Soil(Quality::High) => "Extra Long-Life Soil".to_string(),
+            PriceItem::Additive(OneTimeIngredient::PGR) => "PGR".to_string(),
+            PriceItem::Additive(OneTimeIngredient::Fertilizer) => "Fertilizer".to_string(),
+            PriceItem::Additive(OneTimeIngredient::SpeedGrow) => "Speed Grow".to_string(),
+            PriceItem::Packaging(packaging) => format!("{packaging:?}"),
+        }
+    }
+
+    fn default_price(&self) -> Money {
+        Money::dollars(match self {
+            PriceItem::Ingredient(ingredient) => match ingredient {
+                Ingredient::Cuke => 2,
+                Ingredient::Banana => 2,
+                Ingredient::Paracetamol => 3,
+                Ingredient::Donut => 3,
+                Ingredient::Viagra => 4,
+                Ingredient::MouthWash => 4,
+                Ingredient::FluMedicine => 5,
+                Ingredient::Gasoline => 5,
+                Ingredient::EnergyDrink => 6,
+                Ingredient::MotorOil => 6,
+                Ingredient::MegaBean => 7,
+                Ingredient::Chili => 7,
+                Ingredient::Battery => 8,
+                Ingredient::Iodine => 8,
+                Ingredient::Addy => 9,
+                Ingredient::HorseSemen => 9,
+            },
+            PriceItem::Seed(Effect::Calming) => 30,
+            PriceItem::Seed(Effect::Refreshing) => 35,
+            PriceItem::Seed(Effect::Energizing) => 40,
+            PriceItem::Seed(Effect::Sedating) => 45,
+            PriceItem::Seed(effect) => {
+                unreachable!("{effect:?} is not a valid starting effect for marijuana")
+            }
+            PriceItem::CocaSeed => 80,
+            PriceItem::Pseudo(Quality::Low) => 60,
+            PriceItem::Pseudo(Quality::Medium) => 80,
+            PriceItem::Pseudo(Quality::High) => 110,
+            PriceItem::Acid => 40,
+            PriceItem::RedPhosphorus => 40,
+            PriceItem::Soil(Quality::Low) => 10,
+            PriceItem::Soil(Quality::Medium) => 30,
+            PriceItem::Soil(Quality::High) => 60,
+            PriceItem::Additive(_) => 30,
+            PriceItem::Packaging(Packaging::Baggie) => 1,
+            PriceItem::Packaging(Packaging::Jar) => 3,
+            // pressed, nothing to buy
+            PriceItem::Packaging(Packaging::Brick) => 0,
+        })
+    }
+}
+
+/// What everything costs to buy, editable since prices move with patches and supplier deals.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PriceBook {
+    prices: HashMap<PriceItem, Money>,
+}
+
+impl Default for PriceBook {
+    fn default() -> Self {
+        PriceBook {
+            prices: PriceItem::all()
+                .into_iter()
+                .map(|item| (item, item.default_price()))
+                .collect(),
+        }
+    }
+}
+
+impl PriceBook {
+    pub fn get(&self, item: PriceItem) -> Money {
+        self.prices[&item]
+    }
+
+    pub fn set(&mut self, item: PriceItem, price: Money) {
+        self.prices.insert(item, price);
+    }
+
+    pub fn ingredient(&self, ingredient: Ingredient) -> Money {
+        self.get(PriceItem::Ingredient(ingredient))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defaults() {
+        let prices = PriceBook::default();
+        assert_eq!(prices.ingredient(Ingredient::Cuke), 2.);
+        assert_eq!(prices.ingredient(Ingredient::HorseSemen), 9.);
+        assert_eq!(prices.get(PriceItem::Soil(Quality::High)), 60.);
+        assert_eq!(prices.get(PriceItem::Pseudo(Quality::Medium)), 80.);
+        assert_eq!(
+            prices.get(PriceItem::Additive(OneTimeIngredient::SpeedGrow)),
+            30.
+        );
+        assert_eq!(prices.get(PriceItem::Packaging(Packaging::Jar)), 3.);
+    }
+
+    #[test]
+    fn test_set() {
+        let mut prices = PriceBook::default();
+        prices.set(
+            PriceItem::Ingredient(Ingredient::Cuke),
+            Money::from_f32(1.5).unwrap(),
+        );
+        assert_eq!(prices.ingredient(Ingredient::Cuke), 1.5);
+        assert_ne!(prices, PriceBook::default());
+    }
+}
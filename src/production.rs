@@ -0,0 +1,139 @@
+use crate::price_book::PriceItem;
+use crate::sellable::{Ingredient, MixState, Product};
+
+/// Equipment a batch is worked on at.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Station {
+    Chemistry,
+    Cauldron,
+    LabOven,
+}
+
+impl Station {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Station::Chemistry => "Chemistry Station",
+            Station::Cauldron => "Cauldron",
+            Station::LabOven => "Lab Oven",
+        }
+    }
+}
+
+/// One station's work on a batch.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProductionStep {
+    pub station: Station,
+    /// bought for every batch
+    pub inputs: Vec<PriceItem>,
+    pub hours: f32,
+}
+
+/// The stations a product is cooked at before it can be mixed and sold.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProductionChain {
+    pub steps: Vec<ProductionStep>,
+    /// units of product a batch ends up as
+    pub batch_units: f32,
+    /// harvested coca leaves a batch is cooked from, when the chain starts from a grow
+    pub leaves_per_batch: Option<f32>,
+}
+
+impl ProductionChain {
+    /// The chain for `product`, if it is cooked rather than only grown.
+    pub fn of(product: Product, state: &MixState) -> Option<Self> {
+        match product {
+            Product::Marijuana(_) => None,
+            // pseudo, acid and red phosphorus cook into liquid meth, which
+            // the oven sets into crystal
+            Product::Meth => Some(ProductionChain {
+                steps: vec![
+                    ProductionStep {
+                        station: Station::Chemistry,
+                        inputs: vec![
+                            PriceItem::Pseudo(state.pseudo_quality),
+                            PriceItem::Acid,
+                            PriceItem::RedPhosphorus,
+                        ],
+                        hours: 5.,
+                    },
+                    ProductionStep {
+                        station: Station::LabOven,
+                        inputs: vec![],
+                        hours: 1.,
+                    },
+                ],
+                batch_units: 10.,
+                leaves_per_batch: None,
+            }),
+            // leaves and gasoline boil down to cocaine base, which the oven
+            // bakes into cocaine
+            Product::Cocaine => Some(ProductionChain {
+                steps: vec![
+                    ProductionStep {
+                        station: Station::Cauldron,
+                        inputs: vec![PriceItem::Ingredient(Ingredient::Gasoline)],
+                        hours: 6.,
+                    },
+                    ProductionStep {
+                        station: Station::LabOven,
+                        inputs: vec![],
+                        hours: 1.,
+                    },
+                ],
+                batch_units: 10.,
+                leaves_per_batch: Some(20.),
+            }),
+        }
+    }
+
+    /// Units a harvest of `harvest` leaves is cooked into, or one batch when
+    /// nothing is grown for the chain.
+    pub fn units(&self, harvest: Option<f32>) -> f32 {
+        match (harvest, self.leaves_per_batch) {
+            (Some(leaves), Some(per_batch)) => leaves / per_batch * self.batch_units,
+            _ => self.batch_units,
+        }
+    }
+
+    /// In-game hours to take one batch through every step.
+    pub fn hours(&self) -> f32 {
+        self.steps.iter().map(|step| step.hours).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sellable::{Effect, Quality};
+
+    #[test]
+    fn test_meth() {
+        let state = MixState {
+            pseudo_quality: Quality::High,
+            ..MixState::default()
+        };
+        let chain = ProductionChain::of(Product::Meth, &state).unwrap();
+        assert_eq!(chain.batch_units, 10.);
+        assert_eq!(chain.hours(), 6.);
+        assert!(chain.steps[0]
+            .inputs
+            .contains(&PriceItem::Pseudo(Quality::High)));
+        assert_eq!(chain.units(None), 10.);
+        assert_eq!(
+            ProductionChain::of(Product::Marijuana(Effect::Calming), &state),
+            None
+        );
+    }
+
+    #[test]
+    fn test_cocaine() {
+        let chain = ProductionChain::of(Product::Cocaine, &MixState::default()).unwrap();
+        assert_eq!(chain.hours(), 7.);
+        // a plant's 32 leaves make a batch and a half
+        assert_eq!(chain.units(Some(32.)), 16.);
+        assert_eq!(
+            chain.steps[0].inputs,
+            vec![PriceItem::Ingredient(Ingredient::Gasoline)]
+        );
+    }
+}
@@ -0,0 +1,295 @@
+use crate::sellable::Effect;
+use crate::sellable::Effect::*;
+
+/// Extra requirement on the rest of the mix for a reaction to fire.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Condition {
+    Always,
+    /// only fires when the effect is not already in the mix
+    Without(Effect),
+    /// only fires when the effect is already in the mix
+    With(Effect),
+}
+
+/// A single `(existing, incoming) -> result` row of the reaction table.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Reaction {
+    pub existing: Effect,
+    pub incoming: Effect,
+    pub result: Effect,
+    pub condition: Condition,
+}
+
+impl Reaction {
+    const fn unless(self, effect: Effect) -> Self {
+        Reaction {
+            condition: Condition::Without(effect),
+            ..self
+        }
+    }
+
+    const fn requires(self, effect: Effect) -> Self {
+        Reaction {
+            condition: Condition::With(effect),
+            ..self
+        }
+    }
+
+    pub fn applies(&self, contains: impl Fn(&Effect) -> bool) -> bool {
+        match self.condition {
+            Condition::Always => true,
+            Condition::Without(effect) => !contains(&effect),
+            Condition::With(effect) => contains(&effect),
+        }
+    }
+}
+
+const fn reaction(existing: Effect, incoming: Effect, result: Effect) -> Reaction {
+    Reaction {
+        existing,
+        incoming,
+        result,
+        condition: Condition::Always,
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum TableError {
+    Duplicate(Reaction),
+    Contradiction(Reaction, Reaction),
+}
+
+/// Every known reaction, sorted by existing effect then incoming effect.
+/// Update this table when a game patch changes the mixing rules.
+pub static REACTIONS: &[Reaction] = &[
+    reaction(AntiGravity, CalorieDense, Slippery),
+    reaction(AntiGravity, LongFaced, Calming),
+    reaction(AntiGravity, Spicy, TropicThunder),
+    reaction(Athletic, Foggy, Laxative),
+    reaction(Athletic, Sedating, Munchies),
+    reaction(Athletic, Spicy, Euphoric),
+    reaction(Athletic, TropicThunder, Sneaky),
+    reaction(Balding, CalorieDense, Sneaky),
+    reaction(Calming, Balding, AntiGravity),
+    reaction(Calming, Foggy, Glowing),
+    reaction(Calming, Gingeritis, Sneaky),
+    reaction(Calming, Jennerising, Balding),
+    reaction(Calming, Sedating, BrightEyed),
+    reaction(Calming, Sneaky, Slippery),
+    reaction(CalorieDense, Balding, Sneaky),
+    reaction(CalorieDense, CalorieDense, Explosive),
+    reaction(CalorieDense, Jennerising, Gingeritis),
+    reaction(Cyclopean, BrightEyed, Glowing),
+    reaction(Cyclopean, Gingeritis, ThoughtProvoking),
+    reaction(Cyclopean, Sedating, Foggy),
+    reaction(Disorienting, Athletic, Electrifying),
+    reaction(Disorienting, Gingeritis, Focused),
+    reaction(Disorienting, Toxic, Glowing),
+    reaction(Disorienting, TropicThunder, Toxic),
+    reaction(Electrifying, BrightEyed, Euphoric).unless(Zombifying),
+    reaction(Electrifying, Sedating, Refreshing),
+    reaction(Electrifying, Sneaky, Athletic),
+    reaction(Electrifying, Toxic, Disorienting),
+    reaction(Energizing, Foggy, Cyclopean).unless(ThoughtProvoking),
+    reaction(Energizing, Gingeritis, ThoughtProvoking).unless(Cyclopean),
+    reaction(Energizing, Slippery, Munchies),
+    reaction(Energizing, Sneaky, Paranoia).unless(Munchies),
+    reaction(Energizing, Toxic, Euphoric),
+    reaction(Euphoric, Athletic, Energizing),
+    reaction(Euphoric, BrightEyed, Zombifying).unless(Electrifying),
+    reaction(Euphoric, Energizing, Laxative),
+    reaction(Euphoric, Jennerising, SeizureInducing),
+    reaction(Euphoric, Sedating, Toxic),
+    reaction(Euphoric, Slippery, Sedating),
+    reaction(Euphoric, Toxic, Spicy).unless(Energizing),
+    reaction(Euphoric, TropicThunder, BrightEyed),
+    reaction(Explosive, Balding, Sedating),
+    reaction(Explosive, ThoughtProvoking, Euphoric),
+    reaction(Focused, Athletic, Shrinking),
+    reaction(Focused, Balding, Jennerising),
+    reaction(Focused, CalorieDense, Euphoric),
+    reaction(Focused, Foggy, Disorienting),
+    reaction(Focused, Gingeritis, SeizureInducing),
+    reaction(Focused, Sedating, Calming),
+    reaction(Focused, Sneaky, Gingeritis),
+    reaction(Foggy, Athletic, Laxative),
+    reaction(Foggy, Energizing, Cyclopean),
+    reaction(Foggy, Jennerising, Paranoia),
+    reaction(Foggy, Slippery, Toxic),
+    reaction(Foggy, Sneaky, Calming),
+    reaction(Foggy, ThoughtProvoking, Energizing),
+    reaction(Gingeritis, Energizing, ThoughtProvoking),
+    reaction(Gingeritis, LongFaced, Refreshing),
+    reaction(Gingeritis, Toxic, Smelly),
+    reaction(Glowing, Athletic, Disorienting),
+    reaction(Glowing, Sneaky, Toxic),
+    reaction(Glowing, ThoughtProvoking, Refreshing),
+    reaction(Jennerising, CalorieDense, Gingeritis),
+    reaction(Jennerising, Foggy, Paranoia),
+    reaction(Jennerising, Toxic, Sneaky),
+    reaction(Laxative, BrightEyed, CalorieDense),
+    reaction(Laxative, Sedating, Euphoric),
+    reaction(Laxative, Spicy, LongFaced),
+    reaction(Laxative, Toxic, Foggy),
+    reaction(Laxative, TropicThunder, Calming),
+    reaction(LongFaced, Gingeritis, Refreshing),
+    reaction(LongFaced, ThoughtProvoking, Electrifying),
+    reaction(Munchies, BrightEyed, TropicThunder),
+    reaction(Munchies, Energizing, Athletic),
+    reaction(Munchies, Sedating, Slippery),
+    reaction(Munchies, Slippery, Schizophrenic).unless(Energizing),
+    reaction(Munchies, Sneaky, AntiGravity),
+    reaction(Munchies, Spicy, Toxic),
+    reaction(Munchies, Toxic, Sedating),
+    reaction(Paranoia, Gingeritis, Jennerising),
+    reaction(Paranoia, Slippery, AntiGravity),
+    reaction(Paranoia, Sneaky, Balding),
+    reaction(Paranoia, Toxic, Calming),
+    reaction(Refreshing, Jennerising, ThoughtProvoking),
+    reaction(Schizophrenic, Athletic, Balding),
+    reaction(Sedating, Athletic, Munchies),
+    reaction(Sedating, ThoughtProvoking, Gingeritis),
+    reaction(SeizureInducing, Foggy, Focused),
+    reaction(Shrinking, BrightEyed, Munchies),
+    reaction(Shrinking, CalorieDense, Energizing),
+    reaction(Shrinking, Foggy, Electrifying),
+    reaction(Shrinking, Sedating, Paranoia),
+    reaction(Shrinking, Spicy, Refreshing),
+    reaction(Shrinking, Toxic, Focused),
+    reaction(Slippery, Energizing, Munchies).unless(Munchies),
+    reaction(Slippery, Energizing, Athletic).requires(Munchies),
+    reaction(Slippery, Foggy, Toxic),
+    reaction(Smelly, Gingeritis, AntiGravity),
+    reaction(Sneaky, Energizing, Paranoia),
+    reaction(Sneaky, Foggy, Calming),
+    reaction(Sneaky, Spicy, BrightEyed),
+    reaction(Sneaky, Toxic, TropicThunder),
+    reaction(Spicy, Athletic, Euphoric),
+    reaction(Spicy, Sneaky, BrightEyed),
+    reaction(ThoughtProvoking, Foggy, Cyclopean),
+    reaction(ThoughtProvoking, LongFaced, Electrifying),
+    reaction(ThoughtProvoking, Sedating, Gingeritis),
+    reaction(Toxic, Energizing, Euphoric),
+    reaction(Toxic, Gingeritis, Smelly),
+    reaction(Toxic, Jennerising, Sneaky),
+    reaction(Toxic, Sneaky, TropicThunder),
+    reaction(TropicThunder, Athletic, Sneaky),
+];
+
+impl Condition {
+    /// Whether some mix meets both conditions. Only an effect being both
+    /// present and absent is impossible.
+    fn overlaps(&self, other: &Condition) -> bool {
+        !matches!(
+            (self, other),
+            (Condition::With(a), Condition::Without(b))
+                | (Condition::Without(a), Condition::With(b)) if a == b
+        )
+    }
+}
+
+/// Checks a reaction table for rows that are repeated verbatim, or that
+/// give different results for the same effects when both can fire.
+pub fn validate(rows: &[Reaction]) -> Result<(), Vec<TableError>> {
+    let mut errors = Vec::new();
+    for (i, row) in rows.iter().enumerate() {
+        for other in &rows[i + 1..] {
+            if row.existing != other.existing || row.incoming != other.incoming {
+                continue;
+            }
+            if row == other {
+                errors.push(TableError::Duplicate(*row));
+            } else if row.result != other.result && row.condition.overlaps(&other.condition) {
+                errors.push(TableError::Contradiction(*row, *other));
+            }
+        }
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Reaction rows grouped by incoming effect for quick lookup.
+#[derive(Clone, Debug)]
+pub struct ReactionIndex {
+    by_incoming: Vec<Vec<Reaction>>,
+}
+
+impl ReactionIndex {
+    /// Panics if the rows do not pass [`validate`].
+    pub fn new(rows: &[Reaction]) -> Self {
+        if let Err(errors) = validate(rows) {
+            panic!("invalid reaction table: {errors:?}");
+        }
+        let mut by_incoming = vec![Vec::new(); Effect::ALL.len()];
+        for row in rows {
+            by_incoming[row.incoming as usize].push(*row);
+        }
+        ReactionIndex { by_incoming }
+    }
+
+    /// All reactions triggered by adding an ingredient with the `incoming` effect.
+    pub fn reactions_for(&self, incoming: Effect) -> &[Reaction] {
+        &self.by_incoming[incoming as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_is_valid() {
+        assert_eq!(validate(REACTIONS), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_flags_duplicates() {
+        let rows = [
+            reaction(Calming, Sneaky, Slippery),
+            reaction(Calming, Sneaky, Slippery),
+        ];
+        assert_eq!(validate(&rows), Err(vec![TableError::Duplicate(rows[0])]));
+    }
+
+    #[test]
+    fn test_validate_flags_contradictions() {
+        let rows = [
+            reaction(Calming, Sneaky, Slippery),
+            reaction(Calming, Sneaky, Toxic),
+            reaction(Calming, Sneaky, Toxic).unless(Munchies),
+        ];
+        assert_eq!(
+            validate(&rows),
+            Err(vec![
+                TableError::Contradiction(rows[0], rows[1]),
+                TableError::Contradiction(rows[0], rows[2]),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_validate_allows_complements() {
+        let rows = [
+            reaction(Calming, Sneaky, Slippery).unless(Munchies),
+            reaction(Calming, Sneaky, Toxic).requires(Munchies),
+        ];
+        assert_eq!(validate(&rows), Ok(()));
+        let rows = [
+            reaction(Calming, Sneaky, Slippery).unless(Munchies),
+            reaction(Calming, Sneaky, Toxic).requires(Energizing),
+        ];
+        assert!(validate(&rows).is_err());
+    }
+
+    #[test]
+    fn test_reactions_for() {
+        let index = ReactionIndex::new(REACTIONS);
+        let reactions = index.reactions_for(Energizing);
+        assert!(reactions.iter().all(|row| row.incoming == Energizing));
+        assert!(reactions.contains(&reaction(Slippery, Energizing, Athletic).requires(Munchies)));
+        assert!(index.reactions_for(Lethal).is_empty());
+    }
+}
@@ -0,0 +1,250 @@
+use crate::optimizer::Explorer;
+use crate::sellable::Sellable;
+use dioxus::prelude::*;
+
+/// Recipes mixed, or collected into results, between progress updates. Small
+/// enough to keep a frame short in the browser.
+const CHUNK: usize = 256;
+
+/// Builds a search's results up one explored recipe at a time, so no single
+/// step has to go over everything found so far.
+pub trait Collector: Send {
+    type Output;
+
+    /// Takes a recipe from the newest layer. A recipe with the same effects as
+    /// one added before is a better way there and replaces it.
+    fn add(&mut self, recipe: &Sellable);
+
+    /// The results from every recipe added so far.
+    fn results(&self) -> Self::Output;
+}
+
+/// A search with an [`Explorer`], turned into results by a [`Collector`].
+pub struct SearchJob<T> {
+    explorer: Explorer,
+    collector: Box<dyn Collector<Output = T>>,
+    /// the layer and position in it the collector has been given recipes up to
+    collected: (usize, usize),
+}
+
+impl<T> SearchJob<T> {
+    pub fn new(explorer: Explorer, collector: impl Collector<Output = T> + 'static) -> Self {
+        SearchJob {
+            explorer,
+            collector: Box::new(collector),
+            collected: (0, 0),
+        }
+    }
+
+    /// Runs the whole search on this thread.
+    #[cfg(test)]
+    pub fn run(mut self) -> T {
+        loop {
+            self.collect(usize::MAX);
+            if self.explorer.is_done() {
+                return self.collector.results();
+            }
+            self.explorer.step(usize::MAX);
+        }
+    }
+
+    /// Whether finished layers hold recipes the collector has not been given.
+    fn is_collecting(&self) -> bool {
+        self.collected.0 < self.explorer.layers().len()
+    }
+
+    fn is_done(&self) -> bool {
+        self.explorer.is_done() && !self.is_collecting()
+    }
+
+    /// Gives the collector up to `budget` more recipes, returning whether it
+    /// has caught up with the explorer.
+    fn collect(&mut self, mut budget: usize) -> bool {
+        let layers = self.explorer.layers();
+        let (mut layer, mut position) = self.collected;
+        while layer < layers.len() && budget > 0 {
+            let end = layers[layer].len().min(position.saturating_add(budget));
+            for recipe in &layers[layer][position..end] {
+                self.collector.add(recipe);
+            }
+            budget -= end - position;
+            if end == layers[layer].len() {
+                layer += 1;
+                position = 0;
+            } else {
+                position = end;
+            }
+        }
+        self.collected = (layer, position);
+        !self.is_collecting()
+    }
+
+    /// Does a chunk of work, returning the progress so far and, whenever the
+    /// collector catches up with a finished layer, the results up to there.
+    #[cfg(target_arch = "wasm32")]
+    fn advance(&mut self) -> (f32, Option<T>) {
+        let results = if self.is_collecting() {
+            self.collect(CHUNK).then(|| self.collector.results())
+        } else {
+            self.explorer.step(CHUNK);
+            None
+        };
+        (self.explorer.progress(), results)
+    }
+}
+
+/// A [`SearchJob`] the UI can follow and stop.
+///
+/// `progress` is set while it runs and `results` fills in as each layer
+/// finishes, the last being the full results.
+pub struct Search<T: 'static> {
+    pub progress: Signal<Option<f32>>,
+    pub results: Signal<Option<T>>,
+    task: Signal<Option<Task>>,
+}
+
+impl<T> Clone for Search<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Search<T> {}
+
+pub fn use_search<T: Send + 'static>() -> Search<T> {
+    Search {
+        progress: use_signal(|| None),
+        results: use_signal(|| None),
+        task: use_signal(|| None),
+    }
+}
+
+impl<T: Send + 'static> Search<T> {
+    /// Replaces any running search with `job`.
+    pub fn start(&mut self, job: SearchJob<T>) {
+        self.cancel();
+        self.results.set(None);
+        self.progress.set(Some(0.));
+        let mut progress = self.progress;
+        let mut results = self.results;
+        self.task.set(Some(spawn(async move {
+            run(job, move |update, partial| {
+                progress.set(Some(update));
+                if let Some(partial) = partial {
+                    results.set(Some(partial));
+                }
+            })
+            .await;
+            progress.set(None);
+        })));
+    }
+
+    /// Stops the running search, keeping whatever results it had found.
+    pub fn cancel(&mut self) {
+        if let Some(task) = self.task.take() {
+            task.cancel();
+        }
+        self.progress.set(None);
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.progress.read().is_some()
+    }
+}
+
+/// The browser gives us no threads, so the search shares the UI thread: it
+/// mixes or collects one chunk at a time and hands control back to the
+/// event loop between chunks. Each chunk still blocks the page while it
+/// runs; moving the search into a Web Worker would need a second wasm entry
+/// point and is not done yet.
+#[cfg(target_arch = "wasm32")]
+async fn run<T>(mut job: SearchJob<T>, mut update: impl FnMut(f32, Option<T>)) {
+    loop {
+        let (progress, results) = job.advance();
+        update(progress, results);
+        if job.is_done() {
+            return;
+        }
+        yield_to_browser().await;
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn yield_to_browser() {
+    let promise = js_sys::Promise::new(&mut |resolve, _| {
+        web_sys::window()
+            .unwrap()
+            .set_timeout_with_callback(&resolve)
+            .unwrap();
+    });
+    let _ = wasm_bindgen_futures::JsFuture::from(promise).await;
+}
+
+/// Runs the search on its own thread, which stops at its next update once
+/// the task awaiting it is cancelled and the receiver dropped.
+#[cfg(not(target_arch = "wasm32"))]
+async fn run<T: Send + 'static>(mut job: SearchJob<T>, mut update: impl FnMut(f32, Option<T>)) {
+    use futures_util::StreamExt;
+
+    let (sender, mut receiver) = futures_channel::mpsc::unbounded();
+    std::thread::spawn(move || loop {
+        if job.is_collecting() {
+            job.collect(usize::MAX);
+            let update = (job.explorer.progress(), Some(job.collector.results()));
+            if sender.unbounded_send(update).is_err() {
+                return;
+            }
+        }
+        if job.is_done() {
+            return;
+        }
+        let threads = std::thread::available_parallelism().map_or(1, |threads| threads.get());
+        if !job.explorer.step_layer(threads, CHUNK, |progress| {
+            sender.unbounded_send((progress, None)).is_ok()
+        }) {
+            return;
+        }
+    });
+    while let Some((progress, results)) = receiver.next().await {
+        update(progress, results);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optimizer::Goal;
+    use crate::price_book::PriceBook;
+    use crate::sellable::Product;
+
+    struct Count(usize);
+
+    impl Collector for Count {
+        type Output = usize;
+
+        fn add(&mut self, _: &Sellable) {
+            self.0 += 1;
+        }
+
+        fn results(&self) -> usize {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_collect_in_chunks() {
+        let explorer = Explorer::new(Product::Meth, 2, Goal::Cheapest, PriceBook::default());
+        let mut job = SearchJob::new(explorer, Count(0));
+        assert!(job.collect(5));
+        assert_eq!(job.collector.results(), 1);
+        while !job.explorer.is_done() {
+            job.explorer.step(usize::MAX);
+        }
+        let total = job.explorer.layers().iter().map(Vec::len).sum::<usize>();
+        assert!(!job.collect(3));
+        assert_eq!(job.collector.results(), 4);
+        while !job.collect(3) {}
+        assert_eq!(job.collector.results(), total);
+        assert!(job.is_done());
+    }
+}
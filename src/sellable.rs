@@ -1,6 +1,15 @@
+use crate::effect_set::EffectSet;
+use crate::mixer_map::MixerMap;
+use crate::mixing::{MixEngine, ReactionTable};
+use crate::money::Money;
+use crate::price_book::{PriceBook, PriceItem};
+use crate::production::ProductionChain;
+use crate::transitions::TransitionCache;
 use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[allow(clippy::upper_case_acronyms)]
 pub enum OneTimeIngredient {
     PGR,
     Fertilizer,
@@ -14,40 +23,254 @@ pub struct MixState {
     pub pseudo_quality: Quality,
     /// in oposition to using a grow tent
     pub use_pot: bool,
+    pub prices: PriceBook,
+    pub grow_plan: GrowPlan,
+    /// ranked packaging unlocked so far, baggies are always available
+    pub unlocked_packaging: HashSet<Packaging>,
+}
+
+/// How long grow supplies last before they are bought again.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GrowPlan {
+    /// harvests a bag of plain soil lasts
+    pub soil_harvests: u32,
+    pub long_life_soil_harvests: u32,
+    pub extra_long_life_soil_harvests: u32,
+    /// additives bought for every harvest, the rest are bought once per bag of soil
+    pub per_harvest: HashSet<OneTimeIngredient>,
+}
+
+impl Default for GrowPlan {
+    fn default() -> Self {
+        GrowPlan {
+            soil_harvests: 1,
+            long_life_soil_harvests: 2,
+            extra_long_life_soil_harvests: 3,
+            per_harvest: HashSet::from([
+                OneTimeIngredient::PGR,
+                OneTimeIngredient::Fertilizer,
+                OneTimeIngredient::SpeedGrow,
+            ]),
+        }
+    }
+}
+
+impl GrowPlan {
+    /// How many harvests a bag of soil of this quality lasts.
+    pub fn harvests(&self, soil_quality: Quality) -> u32 {
+        match soil_quality {
+            Quality::Low => self.soil_harvests,
+            Quality::Medium => self.long_life_soil_harvests,
+            Quality::High => self.extra_long_life_soil_harvests,
+        }
+        .max(1)
+    }
+
+    /// How many harvests one purchase of `additive` covers with this soil.
+    pub fn additive_harvests(&self, additive: OneTimeIngredient, soil_quality: Quality) -> u32 {
+        if self.per_harvest.contains(&additive) {
+            1
+        } else {
+            self.harvests(soil_quality)
+        }
+    }
+}
+
+/// In-game hours a mixing station takes to mix one ingredient into one unit.
+pub const MIXING_HOURS: f32 = 0.05;
+
+/// How much of a plant's grow time Speed Grow cuts.
+pub const SPEED_GROW_REDUCTION: f32 = 0.5;
+
+/// The most effects a product can carry, further ingredients only cause reactions.
+pub const MAX_EFFECTS: usize = 8;
+
+/// Effects in the order the game lists them. New effects go on the end and
+/// a reaction replaces an effect in place, so mixing is deterministic.
+///
+/// Hashes as its [`EffectSet`] alone and compares the sets before the order,
+/// so lookups keyed on it cost about as much as ones keyed on the bitset.
+#[derive(Clone, Debug, Default)]
+pub struct EffectList {
+    order: Vec<Effect>,
+    set: EffectSet,
+}
+
+impl EffectList {
+    pub fn iter(&self) -> std::slice::Iter<'_, Effect> {
+        self.order.iter()
+    }
+
+    pub fn contains(&self, effect: &Effect) -> bool {
+        self.set.contains(effect)
+    }
+
+    pub fn len(&self) -> usize {
+        self.set.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.set.is_empty()
+    }
+
+    /// The same effects without their order, cheap to hash and compare.
+    pub fn set(&self) -> EffectSet {
+        self.set
+    }
+
+    /// Appends the effect unless it is already listed or the list is full.
+    pub fn push(&mut self, effect: Effect) -> bool {
+        if self.contains(&effect) || self.len() >= MAX_EFFECTS {
+            return false;
+        }
+        self.order.push(effect);
+        self.set.insert(effect);
+        true
+    }
+
+    /// Swaps `old` for `new` at the same position.
+    pub fn replace(&mut self, old: Effect, new: Effect) {
+        if let Some(slot) = self.order.iter_mut().find(|effect| **effect == old) {
+            *slot = new;
+            self.set.remove(old);
+            self.set.insert(new);
+        }
+    }
+}
+
+impl PartialEq for EffectList {
+    fn eq(&self, other: &Self) -> bool {
+        self.set == other.set && self.order == other.order
+    }
+}
+
+impl Eq for EffectList {}
+
+/// Lists with the same effects in another order share a hash, which only
+/// costs a comparison of the orders on the rare lookups that meet.
+impl Hash for EffectList {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.set.hash(state);
+    }
+}
+
+impl<const N: usize> From<[Effect; N]> for EffectList {
+    fn from(effects: [Effect; N]) -> Self {
+        let mut list = EffectList::default();
+        for effect in effects {
+            list.push(effect);
+        }
+        list
+    }
+}
+
+/// Compares as a set, ignoring the order.
+impl PartialEq<HashSet<Effect>> for EffectList {
+    fn eq(&self, other: &HashSet<Effect>) -> bool {
+        self.set == other.iter().copied().collect()
+    }
+}
+
+/// What happened to the ingredient's own effect during a mix.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NewEffect {
+    Added,
+    AlreadyPresent,
+    /// the product already had [`MAX_EFFECTS`] effects
+    Capped,
+}
+
+/// Report of a single ingredient being mixed in.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MixStep {
+    pub ingredient: Ingredient,
+    /// `(existing, replacement)` reactions that were applied
+    pub fired: Vec<(Effect, Effect)>,
+    /// reactions skipped because the replacement was already present
+    pub blocked: Vec<(Effect, Effect)>,
+    pub new_effect: NewEffect,
+    /// change in [`Sellable::sell_price`]
+    pub price_delta: Money,
+}
+
+impl MixStep {
+    /// The ingredient left the effects exactly as they were.
+    pub fn is_noop(&self) -> bool {
+        self.fired.is_empty() && self.new_effect != NewEffect::Added
+    }
+}
+
+/// What mixing a single ingredient into a recipe would do.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Lookahead {
+    pub ingredient: Ingredient,
+    pub effects: EffectList,
+    pub sell_price: Money,
+    pub price: Money,
+    /// change in [`Sellable::profit`]
+    pub profit_delta: Money,
+}
+
+/// A single purchase that goes into a unit.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CostItem {
+    pub label: String,
+    /// what one purchase costs
+    pub price: Money,
+    /// how many units one purchase is spread over
+    pub units: f32,
+    /// how many harvests or batches one purchase lasts, `None` when bought per unit
+    pub harvests: Option<u32>,
+}
+
+impl CostItem {
+    pub fn per_unit(&self) -> Money {
+        self.price / self.units
+    }
+}
+
+/// The cost of a unit, item by item.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CostBreakdown {
+    pub items: Vec<CostItem>,
+}
+
+impl CostBreakdown {
+    /// The cost of one unit.
+    pub fn total(&self) -> Money {
+        self.items.iter().map(CostItem::per_unit).sum()
+    }
+
+    /// What the mixed in ingredients cost.
+    pub fn ingredients(&self) -> Money {
+        self.items
+            .iter()
+            .filter(|item| item.harvests.is_none())
+            .map(CostItem::per_unit)
+            .sum()
+    }
 }
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Sellable {
     pub base: Product,
     pub name: String,
-    pub effects: HashSet<Effect>,
+    pub effects: EffectList,
     pub ingredients: Vec<Ingredient>,
+    /// indices into `ingredients` that did not change the effects
+    pub wasted: Vec<usize>,
 }
 
 impl Sellable {
-    pub fn sell_price(&self) -> f32 {
-        let multiplier_sum = self
-            .effects
-            .iter()
-            .map(|effect| effect.multiplier())
-            .sum::<f32>();
-        let price = self.base.sell_price() * (1. + multiplier_sum);
-        // mimicking unity roundToInt which on 0.5, rounds towards the even number.
-        if (price.fract() - 0.5).abs() < 0.001 {
-            let int = price.floor();
-            if int % 2. == 0. {
-                int
-            } else {
-                int + 1.
-            }
-        } else {
-            price.round()
-        }
+    pub fn sell_price(&self) -> Money {
+        // multipliers are whole percentages, so only the final rounding is lossy
+        let percent = (self.effects.set().multiplier_sum() * 100.).round() as i64;
+        (self.base.sell_price() * (100 + percent) / 100.).round_game()
     }
     pub fn from_product(product: Product) -> Self {
         let (effects, name) = if let Product::Marijuana(effect) = product {
             (
-                HashSet::from([effect]),
+                EffectList::from([effect]),
                 match effect {
                     Effect::Calming => "OG Kush".to_string(),
                     Effect::Refreshing => "Sour Diesel".to_string(),
@@ -57,404 +280,93 @@ impl Sellable {
                 },
             )
         } else {
-            (HashSet::new(), format!("{:?}", product))
+            (EffectList::default(), format!("{:?}", product))
         };
         Sellable {
             base: product,
             effects,
             ingredients: Vec::new(),
+            wasted: Vec::new(),
             name,
         }
     }
 
+    /// Mixes with the [`ReactionTable`], reusing the shared [`TransitionCache`].
     pub fn add_ingredient(&mut self, ingredient: Ingredient) -> Self {
-        let new_effect = ingredient.effect();
-        let mut reaction_list = vec![];
-        // let vec = new_effect.mix_direction() * new_effect.mix_megnitude();
-        // let mixer_map = MixerMap::from_drug_type(self.drug_type());
-        for effect in self.effects.iter() {
-            // let point =  mixer_map.get_effect(effect).position + vec;
-            // if let Some(replacement) = mixer_map.get_effect_at(point) {
-            //     reaction_list.push((effect.clone(), replacement));
-            // }
-            match (effect, new_effect) {
-                (Effect::AntiGravity, Effect::CalorieDense) => {
-                    reaction_list.push((Effect::AntiGravity, Effect::Slippery))
-                }
-                (Effect::AntiGravity, Effect::LongFaced) => {
-                    reaction_list.push((Effect::AntiGravity, Effect::Calming))
-                }
-                (Effect::AntiGravity, Effect::Spicy) => {
-                    reaction_list.push((Effect::AntiGravity, Effect::TropicThunder))
-                }
-                (Effect::Athletic, Effect::Foggy) => {
-                    reaction_list.push((Effect::Athletic, Effect::Laxative))
-                }
-                (Effect::Athletic, Effect::Sedating) => {
-                    reaction_list.push((Effect::Athletic, Effect::Munchies))
-                }
-                (Effect::Athletic, Effect::Spicy) => {
-                    reaction_list.push((Effect::Athletic, Effect::Euphoric))
-                }
-                (Effect::Athletic, Effect::TropicThunder) => {
-                    reaction_list.push((Effect::Athletic, Effect::Sneaky))
-                }
-                (Effect::Balding, Effect::CalorieDense) => {
-                    reaction_list.push((Effect::Balding, Effect::Sneaky))
-                }
-                (Effect::Calming, Effect::Balding) => {
-                    reaction_list.push((Effect::Calming, Effect::AntiGravity))
-                }
-                (Effect::Calming, Effect::Foggy) => {
-                    reaction_list.push((Effect::Calming, Effect::Glowing))
-                }
-                (Effect::Calming, Effect::Gingeritis) => {
-                    reaction_list.push((Effect::Calming, Effect::Sneaky))
-                }
-                (Effect::Calming, Effect::Jennerising) => {
-                    reaction_list.push((Effect::Calming, Effect::Balding))
-                }
-                (Effect::Calming, Effect::Sedating) => {
-                    reaction_list.push((Effect::Calming, Effect::BrightEyed))
-                }
-                (Effect::Calming, Effect::Sneaky) => {
-                    reaction_list.push((Effect::Calming, Effect::Slippery))
-                }
-                (Effect::CalorieDense, Effect::Balding) => {
-                    reaction_list.push((Effect::CalorieDense, Effect::Sneaky))
-                }
-                (Effect::CalorieDense, Effect::CalorieDense) => {
-                    reaction_list.push((Effect::CalorieDense, Effect::Explosive))
-                }
-                (Effect::CalorieDense, Effect::Jennerising) => {
-                    reaction_list.push((Effect::CalorieDense, Effect::Gingeritis))
-                }
-                (Effect::Cyclopean, Effect::BrightEyed) => {
-                    reaction_list.push((Effect::Cyclopean, Effect::Glowing))
-                }
-                (Effect::Cyclopean, Effect::Gingeritis) => {
-                    reaction_list.push((Effect::Cyclopean, Effect::ThoughtProvoking))
-                }
-                (Effect::Cyclopean, Effect::Sedating) => {
-                    reaction_list.push((Effect::Cyclopean, Effect::Foggy))
-                }
-                (Effect::Disorienting, Effect::Athletic) => {
-                    reaction_list.push((Effect::Disorienting, Effect::Electrifying))
-                }
-                (Effect::Disorienting, Effect::Gingeritis) => {
-                    reaction_list.push((Effect::Disorienting, Effect::Focused))
-                }
-                (Effect::Disorienting, Effect::Toxic) => {
-                    reaction_list.push((Effect::Disorienting, Effect::Glowing))
-                }
-                (Effect::Disorienting, Effect::TropicThunder) => {
-                    reaction_list.push((Effect::Disorienting, Effect::Toxic))
-                }
-                (Effect::Electrifying, Effect::BrightEyed) => {
-                    if !self.effects.contains(&Effect::Zombifying) {
-                        reaction_list.push((Effect::Electrifying, Effect::Euphoric))
-                    }
-                }
-                (Effect::Electrifying, Effect::Sedating) => {
-                    reaction_list.push((Effect::Electrifying, Effect::Refreshing))
-                }
-                (Effect::Electrifying, Effect::Sneaky) => {
-                    reaction_list.push((Effect::Electrifying, Effect::Athletic))
-                }
-                (Effect::Electrifying, Effect::Toxic) => {
-                    reaction_list.push((Effect::Electrifying, Effect::Disorienting))
-                }
-                (Effect::Energizing, Effect::Foggy) => {
-                    if !self.effects.contains(&Effect::ThoughtProvoking) {
-                        reaction_list.push((Effect::Energizing, Effect::Cyclopean))
-                    }
-                }
-                (Effect::Energizing, Effect::Gingeritis) => {
-                    if !self.effects.contains(&Effect::Cyclopean) {
-                        reaction_list.push((Effect::Energizing, Effect::ThoughtProvoking))
-                    }
-                }
-                (Effect::Energizing, Effect::Slippery) => {
-                    reaction_list.push((Effect::Energizing, Effect::Munchies))
-                }
-                (Effect::Energizing, Effect::Sneaky) => {
-                    if !self.effects.contains(&Effect::Munchies) {
-                        reaction_list.push((Effect::Energizing, Effect::Paranoia));
-                    }
-                    // if !self.effects.contains(&Effect::Paranoia) {
-                    //     reaction_list.push((Effect::Energizing, Effect::Balding));
-                    // }
-                }
-                (Effect::Energizing, Effect::Toxic) => {
-                    reaction_list.push((Effect::Energizing, Effect::Euphoric))
-                }
-                (Effect::Euphoric, Effect::Athletic) => {
-                    reaction_list.push((Effect::Euphoric, Effect::Energizing))
-                }
-                (Effect::Euphoric, Effect::BrightEyed) => {
-                    if !self.effects.contains(&Effect::Electrifying) {
-                        reaction_list.push((Effect::Euphoric, Effect::Zombifying))
-                    }
-                }
-                (Effect::Euphoric, Effect::Energizing) => {
-                    reaction_list.push((Effect::Euphoric, Effect::Laxative))
-                }
-                (Effect::Euphoric, Effect::Jennerising) => {
-                    reaction_list.push((Effect::Euphoric, Effect::SeizureInducing))
-                }
-                (Effect::Euphoric, Effect::Sedating) => {
-                    reaction_list.push((Effect::Euphoric, Effect::Toxic))
-                }
-                (Effect::Euphoric, Effect::Slippery) => {
-                    reaction_list.push((Effect::Euphoric, Effect::Sedating))
-                }
-                (Effect::Euphoric, Effect::Toxic) => {
-                    if !self.effects.contains(&Effect::Energizing) {
-                        reaction_list.push((Effect::Euphoric, Effect::Spicy))
-                    }
-                }
-                (Effect::Euphoric, Effect::TropicThunder) => {
-                    reaction_list.push((Effect::Euphoric, Effect::BrightEyed))
-                }
-                (Effect::Explosive, Effect::Balding) => {
-                    reaction_list.push((Effect::Explosive, Effect::Sedating))
-                }
-                (Effect::Explosive, Effect::ThoughtProvoking) => {
-                    reaction_list.push((Effect::Explosive, Effect::Euphoric))
-                }
-                (Effect::Focused, Effect::Athletic) => {
-                    reaction_list.push((Effect::Focused, Effect::Shrinking))
-                }
-                (Effect::Focused, Effect::Balding) => {
-                    reaction_list.push((Effect::Focused, Effect::Jennerising))
-                }
-                (Effect::Focused, Effect::CalorieDense) => {
-                    reaction_list.push((Effect::Focused, Effect::Euphoric))
-                }
-                (Effect::Focused, Effect::Foggy) => {
-                    reaction_list.push((Effect::Focused, Effect::Disorienting))
-                }
-                (Effect::Focused, Effect::Gingeritis) => {
-                    reaction_list.push((Effect::Focused, Effect::SeizureInducing))
-                }
-                (Effect::Focused, Effect::Sedating) => {
-                    reaction_list.push((Effect::Focused, Effect::Calming))
-                }
-                (Effect::Focused, Effect::Sneaky) => {
-                    reaction_list.push((Effect::Focused, Effect::Gingeritis))
-                }
-                (Effect::Foggy, Effect::Athletic) => {
-                    reaction_list.push((Effect::Foggy, Effect::Laxative))
-                }
-                (Effect::Foggy, Effect::Energizing) => {
-                    reaction_list.push((Effect::Foggy, Effect::Cyclopean))
-                }
-                (Effect::Foggy, Effect::Jennerising) => {
-                    reaction_list.push((Effect::Foggy, Effect::Paranoia))
-                }
-                (Effect::Foggy, Effect::Slippery) => {
-                    reaction_list.push((Effect::Foggy, Effect::Toxic))
-                }
-                (Effect::Foggy, Effect::Sneaky) => {
-                    reaction_list.push((Effect::Foggy, Effect::Calming))
-                }
-                (Effect::Foggy, Effect::ThoughtProvoking) => {
-                    reaction_list.push((Effect::Foggy, Effect::Energizing))
-                }
-                (Effect::Gingeritis, Effect::Energizing) => {
-                    reaction_list.push((Effect::Gingeritis, Effect::ThoughtProvoking))
-                }
-                (Effect::Gingeritis, Effect::LongFaced) => {
-                    reaction_list.push((Effect::Gingeritis, Effect::Refreshing))
-                }
-                (Effect::Gingeritis, Effect::Toxic) => {
-                    reaction_list.push((Effect::Gingeritis, Effect::Smelly))
-                }
-                (Effect::Glowing, Effect::Athletic) => {
-                    reaction_list.push((Effect::Glowing, Effect::Disorienting))
-                }
-                (Effect::Glowing, Effect::Sneaky) => {
-                    reaction_list.push((Effect::Glowing, Effect::Toxic))
-                }
-                (Effect::Glowing, Effect::ThoughtProvoking) => {
-                    reaction_list.push((Effect::Glowing, Effect::Refreshing))
-                }
-                (Effect::Jennerising, Effect::CalorieDense) => {
-                    reaction_list.push((Effect::Jennerising, Effect::Gingeritis))
-                }
-                (Effect::Jennerising, Effect::Foggy) => {
-                    reaction_list.push((Effect::Jennerising, Effect::Paranoia))
-                }
-                (Effect::Jennerising, Effect::Toxic) => {
-                    reaction_list.push((Effect::Jennerising, Effect::Sneaky))
-                }
-                (Effect::Laxative, Effect::BrightEyed) => {
-                    reaction_list.push((Effect::Laxative, Effect::CalorieDense))
-                }
-                (Effect::Laxative, Effect::Sedating) => {
-                    reaction_list.push((Effect::Laxative, Effect::Euphoric))
-                }
-                (Effect::Laxative, Effect::Spicy) => {
-                    reaction_list.push((Effect::Laxative, Effect::LongFaced))
-                }
-                (Effect::Laxative, Effect::Toxic) => {
-                    reaction_list.push((Effect::Laxative, Effect::Foggy))
-                }
-                (Effect::Laxative, Effect::TropicThunder) => {
-                    reaction_list.push((Effect::Laxative, Effect::Calming))
-                }
-                (Effect::LongFaced, Effect::Gingeritis) => {
-                    reaction_list.push((Effect::LongFaced, Effect::Refreshing))
-                }
-                (Effect::LongFaced, Effect::ThoughtProvoking) => {
-                    reaction_list.push((Effect::LongFaced, Effect::Electrifying))
-                }
-                (Effect::Munchies, Effect::BrightEyed) => {
-                    reaction_list.push((Effect::Munchies, Effect::TropicThunder))
-                }
-                (Effect::Munchies, Effect::Energizing) => {
-                    reaction_list.push((Effect::Munchies, Effect::Athletic))
-                }
-                (Effect::Munchies, Effect::Sedating) => {
-                    reaction_list.push((Effect::Munchies, Effect::Slippery))
-                }
-                (Effect::Munchies, Effect::Slippery) => {
-                    if !self.effects.contains(&Effect::Energizing) {
-                        reaction_list.push((Effect::Munchies, Effect::Schizophrenic))
-                    }
-                }
-                (Effect::Munchies, Effect::Sneaky) => {
-                    reaction_list.push((Effect::Munchies, Effect::AntiGravity))
-                }
-                (Effect::Munchies, Effect::Spicy) => {
-                    reaction_list.push((Effect::Munchies, Effect::Toxic))
-                }
-                (Effect::Munchies, Effect::Toxic) => {
-                    reaction_list.push((Effect::Munchies, Effect::Sedating))
-                }
-                (Effect::Paranoia, Effect::Gingeritis) => {
-                    reaction_list.push((Effect::Paranoia, Effect::Jennerising))
-                }
-                (Effect::Paranoia, Effect::Slippery) => {
-                    reaction_list.push((Effect::Paranoia, Effect::AntiGravity))
-                }
-                (Effect::Paranoia, Effect::Sneaky) => {
-                    reaction_list.push((Effect::Paranoia, Effect::Balding))
-                }
-                (Effect::Paranoia, Effect::Toxic) => {
-                    reaction_list.push((Effect::Paranoia, Effect::Calming))
-                }
-                (Effect::Refreshing, Effect::Jennerising) => {
-                    reaction_list.push((Effect::Refreshing, Effect::ThoughtProvoking))
-                }
-                (Effect::Schizophrenic, Effect::Athletic) => {
-                    reaction_list.push((Effect::Schizophrenic, Effect::Balding))
-                }
-                (Effect::Sedating, Effect::Athletic) => {
-                    reaction_list.push((Effect::Sedating, Effect::Munchies))
-                }
-                (Effect::Sedating, Effect::ThoughtProvoking) => {
-                    reaction_list.push((Effect::Sedating, Effect::Gingeritis))
-                }
-                (Effect::SeizureInducing, Effect::Foggy) => {
-                    reaction_list.push((Effect::SeizureInducing, Effect::Focused))
-                }
-                (Effect::Shrinking, Effect::BrightEyed) => {
-                    reaction_list.push((Effect::Shrinking, Effect::Munchies))
-                }
-                (Effect::Shrinking, Effect::CalorieDense) => {
-                    reaction_list.push((Effect::Shrinking, Effect::Energizing))
-                }
-                (Effect::Shrinking, Effect::Foggy) => {
-                    reaction_list.push((Effect::Shrinking, Effect::Electrifying))
-                }
-                (Effect::Shrinking, Effect::Sedating) => {
-                    reaction_list.push((Effect::Shrinking, Effect::Paranoia))
-                }
-                (Effect::Shrinking, Effect::Spicy) => {
-                    reaction_list.push((Effect::Shrinking, Effect::Refreshing))
-                }
-                (Effect::Shrinking, Effect::Toxic) => {
-                    reaction_list.push((Effect::Shrinking, Effect::Focused))
-                }
-                (Effect::Slippery, Effect::Energizing) => {
-                    reaction_list.push((Effect::Slippery, Effect::Munchies));
-                    if self.effects.contains(&Effect::Munchies) {
-                        reaction_list.push((Effect::Slippery, Effect::Athletic));
-                    }
-                }
-                (Effect::Slippery, Effect::Foggy) => {
-                    reaction_list.push((Effect::Slippery, Effect::Toxic))
-                }
-                (Effect::Smelly, Effect::Gingeritis) => {
-                    reaction_list.push((Effect::Smelly, Effect::AntiGravity))
-                }
-                (Effect::Sneaky, Effect::Energizing) => {
-                    reaction_list.push((Effect::Sneaky, Effect::Paranoia))
-                }
-                (Effect::Sneaky, Effect::Foggy) => {
-                    reaction_list.push((Effect::Sneaky, Effect::Calming))
-                }
-                (Effect::Sneaky, Effect::Spicy) => {
-                    reaction_list.push((Effect::Sneaky, Effect::BrightEyed))
-                }
-                (Effect::Sneaky, Effect::Toxic) => {
-                    reaction_list.push((Effect::Sneaky, Effect::TropicThunder))
-                }
-                (Effect::Spicy, Effect::Athletic) => {
-                    reaction_list.push((Effect::Spicy, Effect::Euphoric))
-                }
-                (Effect::Spicy, Effect::Sneaky) => {
-                    reaction_list.push((Effect::Spicy, Effect::BrightEyed))
-                }
-                (Effect::ThoughtProvoking, Effect::Foggy) => {
-                    reaction_list.push((Effect::ThoughtProvoking, Effect::Cyclopean))
-                }
-                (Effect::ThoughtProvoking, Effect::LongFaced) => {
-                    reaction_list.push((Effect::ThoughtProvoking, Effect::Electrifying))
-                }
-                (Effect::ThoughtProvoking, Effect::Sedating) => {
-                    reaction_list.push((Effect::ThoughtProvoking, Effect::Gingeritis))
-                }
-                (Effect::Toxic, Effect::Energizing) => {
-                    reaction_list.push((Effect::Toxic, Effect::Euphoric))
-                }
-                (Effect::Toxic, Effect::Gingeritis) => {
-                    reaction_list.push((Effect::Toxic, Effect::Smelly))
-                }
-                (Effect::Toxic, Effect::Jennerising) => {
-                    reaction_list.push((Effect::Toxic, Effect::Sneaky))
-                }
-                (Effect::Toxic, Effect::Sneaky) => {
-                    reaction_list.push((Effect::Toxic, Effect::TropicThunder))
-                }
-                (Effect::TropicThunder, Effect::Athletic) => {
-                    reaction_list.push((Effect::TropicThunder, Effect::Sneaky))
-                }
-                _ => {}
-            }
+        let effects = TransitionCache::shared().next(self, ingredient);
+        self.with_effects(ingredient, effects)
+    }
+
+    /// Like [`Sellable::add_ingredient`] but an ingredient that changes nothing
+    /// is still recorded, marked as wasted, so it is paid for in [`Sellable::price`].
+    pub fn add_ingredient_recorded(&self, ingredient: Ingredient) -> Self {
+        let mut mixed = self.mix(&ReactionTable, ingredient);
+        if mixed.ingredients.len() == self.ingredients.len() {
+            mixed.wasted.push(mixed.ingredients.len());
+            mixed.ingredients.push(ingredient);
+            mixed.name = format!("{} + {:?}", self.name, ingredient);
         }
+        mixed
+    }
+
+    pub fn is_wasted(&self, index: usize) -> bool {
+        self.wasted.contains(&index)
+    }
+
+    pub fn add_ingredient_traced(&self, ingredient: Ingredient) -> (Self, MixStep) {
+        self.mix_traced(&ReactionTable, ingredient)
+    }
+
+    pub fn mix(&self, engine: &impl MixEngine, ingredient: Ingredient) -> Self {
+        self.mix_traced(engine, ingredient).0
+    }
+
+    /// Mixes like [`Sellable::mix`], also reporting what happened along the way.
+    pub fn mix_traced(&self, engine: &impl MixEngine, ingredient: Ingredient) -> (Self, MixStep) {
+        let new_effect = ingredient.effect();
+        let map = MixerMap::for_product(self.base);
+        let reaction_list = engine.reactions(map, &self.effects, new_effect);
         let mut effects = self.effects.clone();
+        let mut fired = vec![];
+        let mut blocked = vec![];
         for (old, new) in reaction_list {
             if !effects.contains(&new) {
-                effects.remove(&old);
-                effects.insert(new);
+                effects.replace(old, new);
+                fired.push((old, new));
+            } else {
+                blocked.push((old, new));
             }
         }
-        if !effects.contains(&new_effect) && effects.iter().len() < 8 {
-            effects.insert(new_effect);
-        }
+        let outcome = if effects.contains(&new_effect) {
+            NewEffect::AlreadyPresent
+        } else if effects.push(new_effect) {
+            NewEffect::Added
+        } else {
+            NewEffect::Capped
+        };
 
-        if effects == self.effects {
+        let mixed = self.with_effects(ingredient, effects);
+        let step = MixStep {
+            ingredient,
+            fired,
+            blocked,
+            new_effect: outcome,
+            price_delta: mixed.sell_price() - self.sell_price(),
+        };
+        (mixed, step)
+    }
+
+    fn with_effects(&self, ingredient: Ingredient, effects: EffectList) -> Self {
+        if effects.set() == self.effects.set() {
             // if there are no changes, return self
             return self.clone();
         }
-
         let mut ingredients = self.ingredients.clone();
         ingredients.push(ingredient);
         Sellable {
             base: self.base,
             ingredients,
+            wasted: self.wasted.clone(),
             effects,
             name: format!("{} + {:?}", self.name, ingredient),
         }
@@ -466,47 +378,185 @@ impl Sellable {
         new
     }
 
-    pub fn unit_price(&self, state: MixState) -> f32 {
-        self.base.price(state.clone()) / self.yield_amount(state.clone())
+    /// Everything bought to make one unit, spread over the units each purchase covers.
+    pub fn cost_breakdown(&self, state: MixState) -> CostBreakdown {
+        let prices = &state.prices;
+        let yield_amount = self.yield_amount(state.clone());
+        let mut items = vec![];
+        if let Some(seed) = self.base.seed() {
+            items.push(CostItem {
+                label: seed.label(),
+                price: prices.get(seed),
+                units: yield_amount,
+                harvests: Some(1),
+            });
+        }
+        if let Product::Marijuana(_) | Product::Cocaine = self.base {
+            let soil = PriceItem::Soil(state.soil_quality);
+            let harvests = state.grow_plan.harvests(state.soil_quality);
+            items.push(CostItem {
+                label: soil.label(),
+                price: prices.get(soil),
+                units: yield_amount * harvests as f32,
+                harvests: Some(harvests),
+            });
+            for additive in [
+                OneTimeIngredient::PGR,
+                OneTimeIngredient::Fertilizer,
+                OneTimeIngredient::SpeedGrow,
+            ] {
+                if state.ingredients.contains(&additive) {
+                    let harvests = state
+                        .grow_plan
+                        .additive_harvests(additive, state.soil_quality);
+                    let additive = PriceItem::Additive(additive);
+                    items.push(CostItem {
+                        label: additive.label(),
+                        price: prices.get(additive),
+                        units: yield_amount * harvests as f32,
+                        harvests: Some(harvests),
+                    });
+                }
+            }
+        }
+        if let Some(chain) = ProductionChain::of(self.base, &state) {
+            for input in chain.steps.iter().flat_map(|step| &step.inputs) {
+                items.push(CostItem {
+                    label: input.label(),
+                    price: prices.get(*input),
+                    units: chain.batch_units,
+                    harvests: Some(1),
+                });
+            }
+        }
+        for (index, ingredient) in self.ingredients.iter().enumerate() {
+            items.push(CostItem {
+                label: if self.is_wasted(index) {
+                    format!("{ingredient:?} (wasted)")
+                } else {
+                    format!("{ingredient:?}")
+                },
+                price: prices.ingredient(*ingredient),
+                units: 1.,
+                harvests: None,
+            });
+        }
+        CostBreakdown { items }
+    }
+
+    pub fn price(&self, state: MixState) -> Money {
+        self.cost_breakdown(state).total()
     }
 
-    pub fn price(&self, state: MixState) -> f32 {
-        let mut price = self.unit_price(state.clone());
-        let soil_price = match state.soil_quality {
-            Quality::Low => 10.,
-            Quality::Medium => 30.,
-            Quality::High => 60.,
-        };
-        let pgr_price = if state.ingredients.contains(&OneTimeIngredient::PGR) {
-            30.
-        } else {
-            0.
-        };
-        let fertilizer_price = if state.ingredients.contains(&OneTimeIngredient::Fertilizer) {
-            30.
-        } else {
-            0.
-        };
-        let speed_grow_price = if state.ingredients.contains(&OneTimeIngredient::SpeedGrow) {
-            30.
-        } else {
-            0.
-        };
-        price += match (self.base,) {
-            (Product::Marijuana(_) | Product::Cocaine,) => {
-                (soil_price + pgr_price + fertilizer_price + speed_grow_price)
-                    / self.yield_amount(state.clone())
-            }
-            _ => 0.,
+    /// [`Sellable::price`] with each soil quality, over that soil's whole lifetime.
+    pub fn soil_prices(&self, state: MixState) -> Vec<(Quality, Money)> {
+        [Quality::Low, Quality::Medium, Quality::High]
+            .into_iter()
+            .map(|soil_quality| {
+                let state = MixState {
+                    soil_quality,
+                    ..state.clone()
+                };
+                (soil_quality, self.price(state))
+            })
+            .collect()
+    }
+
+    /// The outcome of adding each ingredient, in [`Ingredient::ALL`] order,
+    /// with ingredients that change nothing paid for when `keep_wasted` is set.
+    pub fn lookahead(&self, state: MixState, keep_wasted: bool) -> Vec<Lookahead> {
+        let profit = self.profit(state.clone());
+        Ingredient::ALL
+            .iter()
+            .map(|ingredient| {
+                let mixed = if keep_wasted {
+                    self.add_ingredient_recorded(*ingredient)
+                } else {
+                    self.clone().add_ingredient(*ingredient)
+                };
+                Lookahead {
+                    ingredient: *ingredient,
+                    sell_price: mixed.sell_price(),
+                    price: mixed.price(state.clone()),
+                    profit_delta: mixed.profit(state.clone()) - profit,
+                    effects: mixed.effects,
+                }
+            })
+            .collect()
+    }
+
+    /// Sell price less the total cost of one unit.
+    pub fn profit(&self, state: MixState) -> Money {
+        self.sell_price() - self.price(state)
+    }
+
+    /// Profit as a percentage of the sell price.
+    pub fn margin(&self, state: MixState) -> f32 {
+        self.profit(state).to_f32() / self.sell_price().to_f32() * 100.
+    }
+
+    /// Profit as a percentage of what the mixed in ingredients cost, if any were.
+    pub fn ingredient_return(&self, state: MixState) -> Option<f32> {
+        let spend = self.cost_breakdown(state.clone()).ingredients();
+        (spend > Money::ZERO).then(|| self.profit(state).to_f32() / spend.to_f32() * 100.)
+    }
+
+    pub fn package_sell_price(&self, packaging: Packaging) -> Money {
+        self.sell_price() * packaging.units() as i64
+    }
+
+    /// The cost of a package's units and the container they go in.
+    pub fn package_price(&self, packaging: Packaging, state: MixState) -> Money {
+        let container = state.prices.get(PriceItem::Packaging(packaging));
+        self.price(state) * packaging.units() as i64 + container
+    }
+
+    pub fn package_profit(&self, packaging: Packaging, state: MixState) -> Money {
+        self.package_sell_price(packaging) - self.package_price(packaging, state)
+    }
+
+    /// The unlocked packaging that makes the most from each unit, the smallest
+    /// on ties.
+    pub fn best_packaging(&self, state: MixState) -> Packaging {
+        let unit_profit = |packaging: Packaging| {
+            self.package_profit(packaging, state.clone()) / packaging.units() as f32
         };
-        for ingredient in &self.ingredients {
-            price += ingredient.price();
+        Packaging::ALL
+            .into_iter()
+            .filter(|packaging| packaging.is_unlocked(&state))
+            .reduce(|best, packaging| {
+                if unit_profit(packaging) > unit_profit(best) {
+                    packaging
+                } else {
+                    best
+                }
+            })
+            .unwrap()
+    }
+
+    /// In-game hours to grow or cook a harvest or batch and mix every unit of it.
+    pub fn batch_hours(&self, state: MixState) -> f32 {
+        let yield_amount = self.yield_amount(state.clone());
+        let mut production = self.base.grow_hours();
+        if state.ingredients.contains(&OneTimeIngredient::SpeedGrow) {
+            production *= 1. - SPEED_GROW_REDUCTION;
+        }
+        if let Some(chain) = ProductionChain::of(self.base, &state) {
+            production += chain.hours() * yield_amount / chain.batch_units;
         }
-        price
+        production + yield_amount * self.ingredients.len() as f32 * MIXING_HOURS
+    }
+
+    /// Profit from an in-game hour spent making the product, so additives
+    /// that save time are weighed against what they cost.
+    pub fn profit_per_hour(&self, state: MixState) -> Money {
+        let unit_hours = self.batch_hours(state.clone()) / self.yield_amount(state.clone());
+        self.profit(state) / unit_hours
     }
 
     pub fn addictiveness(&self) -> f32 {
-        let mut total_addictiveness = self.base.addictiveness() + self.effects.iter().map(|i| i.addictiveness()).sum::<f32>();
+        let mut total_addictiveness =
+            self.base.addictiveness() + self.effects.set().addictiveness_sum();
         if let Product::Marijuana(_) = self.base {
             if self.ingredients.is_empty() {
                 total_addictiveness -= self.base.addictiveness();
@@ -524,26 +574,17 @@ impl Sellable {
         key
     }
 
+    /// Units of product from a harvest or batch.
     pub fn yield_amount(&self, state: MixState) -> f32 {
-        match (
-            self.base,
-            state.use_pot,
-            state.ingredients.contains(&OneTimeIngredient::PGR),
-        ) {
-            (Product::Marijuana(_), false, false) => 8.,
-            (Product::Marijuana(_), true, false) => 12.,
-            (Product::Marijuana(_), false, true) => 12.,
-            (Product::Marijuana(_), true, true) => 16.,
-            (Product::Cocaine, false, false) => 6.,
-            (Product::Cocaine, true, false) => 9.,
-            (Product::Cocaine, false, true) => 11.,
-            (Product::Cocaine, true, true) => 16.,
-            (Product::Meth, _, _) => 10.,
+        let harvest = self.base.harvest_yield(&state);
+        match ProductionChain::of(self.base, &state) {
+            Some(chain) => chain.units(harvest),
+            None => harvest.expect("products without a production chain are grown"),
         }
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Default)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
 pub enum Quality {
     #[default]
     Low,
@@ -551,7 +592,65 @@ pub enum Quality {
     High,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+/// How good the finished product is, which customers have standards for.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ProductQuality {
+    Trash,
+    Poor,
+    Standard,
+    Premium,
+    Heavenly,
+}
+
+impl ProductQuality {
+    pub const ALL: [ProductQuality; 5] = [
+        ProductQuality::Trash,
+        ProductQuality::Poor,
+        ProductQuality::Standard,
+        ProductQuality::Premium,
+        ProductQuality::Heavenly,
+    ];
+
+    /// `steps` levels above the lowest, up to the highest.
+    fn from_steps(steps: usize) -> Self {
+        ProductQuality::ALL[steps.min(ProductQuality::ALL.len() - 1)]
+    }
+}
+
+/// What units are sold in.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Packaging {
+    Baggie,
+    Jar,
+    Brick,
+}
+
+impl Packaging {
+    pub const ALL: [Packaging; 3] = [Packaging::Baggie, Packaging::Jar, Packaging::Brick];
+
+    pub fn units(&self) -> u32 {
+        match self {
+            Packaging::Baggie => 1,
+            Packaging::Jar => 5,
+            Packaging::Brick => 20,
+        }
+    }
+
+    /// The rank it unlocks at, if it is not available from the start.
+    pub fn unlock(&self) -> Option<&'static str> {
+        match self {
+            Packaging::Baggie => None,
+            Packaging::Jar => Some("Hoodlum III"),
+            Packaging::Brick => Some("Hustler III"),
+        }
+    }
+
+    pub fn is_unlocked(&self, state: &MixState) -> bool {
+        self.unlock().is_none() || state.unlocked_packaging.contains(self)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum Product {
     Marijuana(Effect),
     Meth,
@@ -559,29 +658,78 @@ pub enum Product {
 }
 
 impl Product {
-    pub fn price(&self, state: MixState) -> f32 {
+    /// What is planted for a harvest, if the product is grown.
+    pub fn seed(&self) -> Option<PriceItem> {
         match self {
-            Product::Marijuana(Effect::Calming) => 30.,
-            Product::Marijuana(Effect::Refreshing) => 35.,
-            Product::Marijuana(Effect::Energizing) => 40.,
-            Product::Marijuana(Effect::Sedating) => 45.,
+            Product::Marijuana(effect) => Some(PriceItem::Seed(*effect)),
+            Product::Meth => None,
+            Product::Cocaine => Some(PriceItem::CocaSeed),
+        }
+    }
+
+    /// What a plant harvests, buds for marijuana and leaves for coca, if
+    /// the product is grown.
+    pub fn harvest_yield(&self, state: &MixState) -> Option<f32> {
+        let pgr = state.ingredients.contains(&OneTimeIngredient::PGR);
+        match (self, state.use_pot, pgr) {
+            (Product::Marijuana(_), false, false) => Some(8.),
+            (Product::Marijuana(_), true, false) => Some(12.),
+            (Product::Marijuana(_), false, true) => Some(12.),
+            (Product::Marijuana(_), true, true) => Some(16.),
+            // placeholders until counted in game, see `is_harvest_measured`:
+            // the per-plant unit yields from before cooking was modelled, at
+            // two leaves for each unit a batch cooks into
+            (Product::Cocaine, false, false) => Some(12.),
+            (Product::Cocaine, true, false) => Some(18.),
+            (Product::Cocaine, false, true) => Some(22.),
+            (Product::Cocaine, true, true) => Some(32.),
+            (Product::Meth, _, _) => None,
+        }
+    }
+
+    /// Whether [`Product::harvest_yield`] was counted in game rather than
+    /// standing in until it is.
+    pub fn is_harvest_measured(&self) -> bool {
+        !matches!(self, Product::Cocaine)
+    }
+
+    /// Poor, standard or premium with low, medium or high quality soil or
+    /// pseudo, and fertilizer makes a plant one level better.
+    pub fn quality(&self, state: &MixState) -> ProductQuality {
+        let input_steps = |quality: Quality| match quality {
+            Quality::Low => 1,
+            Quality::Medium => 2,
+            Quality::High => 3,
+        };
+        match self {
+            Product::Meth => ProductQuality::from_steps(input_steps(state.pseudo_quality)),
+            Product::Marijuana(_) | Product::Cocaine => {
+                let fertilizer = state.ingredients.contains(&OneTimeIngredient::Fertilizer);
+                ProductQuality::from_steps(input_steps(state.soil_quality) + fertilizer as usize)
+            }
+        }
+    }
+
+    /// In-game hours to grow a harvest, without additives.
+    pub fn grow_hours(&self) -> f32 {
+        match self {
+            Product::Marijuana(Effect::Calming) => 48.,
+            Product::Marijuana(Effect::Refreshing) => 48.,
+            Product::Marijuana(Effect::Energizing) => 42.,
+            Product::Marijuana(Effect::Sedating) => 54.,
             Product::Marijuana(effect) => {
                 unreachable!("{effect:?} is not a valid starting effect for marijuana")
             }
-            Product::Meth => match state.pseudo_quality {
-                Quality::Low => 60.,
-                Quality::Medium => 80.,
-                Quality::High => 110.,
-            },
-            Product::Cocaine => 80.,
+            Product::Meth => 0.,
+            Product::Cocaine => 72.,
         }
     }
 
-    pub fn sell_price(&self) -> f32 {
+    pub fn sell_price(&self) -> Money {
         match self {
-            Product::Marijuana(_) => 35.,
-            Product::Meth => 70.,
-            Product::Cocaine => 150.,
+            Product::Marijuana(_) => Money::dollars(35),
+            Product::Meth => Money::dollars(70),
+            Product::Cocaine => Money::dollars(150),
         }
     }
 
@@ -594,7 +742,7 @@ impl Product {
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum Ingredient {
     Cuke,
     Banana,
@@ -615,26 +763,25 @@ pub enum Ingredient {
 }
 
 impl Ingredient {
-    pub fn price(&self) -> f32 {
-        match self {
-            Ingredient::Cuke => 2.,
-            Ingredient::Banana => 2.,
-            Ingredient::Paracetamol => 3.,
-            Ingredient::Donut => 3.,
-            Ingredient::Viagra => 4.,
-            Ingredient::MouthWash => 4.,
-            Ingredient::FluMedicine => 5.,
-            Ingredient::Gasoline => 5.,
-            Ingredient::EnergyDrink => 6.,
-            Ingredient::MotorOil => 6.,
-            Ingredient::MegaBean => 7.,
-            Ingredient::Chili => 7.,
-            Ingredient::Battery => 8.,
-            Ingredient::Iodine => 8.,
-            Ingredient::Addy => 9.,
-            Ingredient::HorseSemen => 9.,
-        }
-    }
+    pub const ALL: [Ingredient; 16] = [
+        Ingredient::Cuke,
+        Ingredient::Banana,
+        Ingredient::Paracetamol,
+        Ingredient::Donut,
+        Ingredient::Viagra,
+        Ingredient::MouthWash,
+        Ingredient::FluMedicine,
+        Ingredient::Gasoline,
+        Ingredient::EnergyDrink,
+        Ingredient::MotorOil,
+        Ingredient::MegaBean,
+        Ingredient::Chili,
+        Ingredient::Battery,
+        Ingredient::Iodine,
+        Ingredient::Addy,
+        Ingredient::HorseSemen,
+    ];
+
     pub fn effect(&self) -> Effect {
         match self {
             Ingredient::Cuke => Effect::Energizing,
@@ -697,7 +844,45 @@ pub enum Effect {
 }
 
 impl Effect {
-    pub fn multiplier(&self) -> f32 {
+    pub const ALL: [Effect; 35] = [
+        Effect::AntiGravity,
+        Effect::Athletic,
+        Effect::Balding,
+        Effect::BrightEyed,
+        Effect::Calming,
+        Effect::CalorieDense,
+        Effect::Cyclopean,
+        Effect::Disorienting,
+        Effect::Electrifying,
+        Effect::Energizing,
+        Effect::Euphoric,
+        Effect::Explosive,
+        Effect::Focused,
+        Effect::Foggy,
+        Effect::Gingeritis,
+        Effect::Glowing,
+        Effect::Jennerising,
+        Effect::Laxative,
+        Effect::Lethal,
+        Effect::LongFaced,
+        Effect::Munchies,
+        Effect::Paranoia,
+        Effect::Refreshing,
+        Effect::Schizophrenic,
+        Effect::Sedating,
+        Effect::SeizureInducing,
+        Effect::Shrinking,
+        Effect::Slippery,
+        Effect::Smelly,
+        Effect::Sneaky,
+        Effect::Spicy,
+        Effect::ThoughtProvoking,
+        Effect::Toxic,
+        Effect::TropicThunder,
+        Effect::Zombifying,
+    ];
+
+    pub const fn multiplier(&self) -> f32 {
         match self {
             Effect::AntiGravity => 0.54,
             Effect::Athletic => 0.32,
@@ -737,7 +922,7 @@ impl Effect {
         }
     }
 
-    pub fn addictiveness(&self) -> f32 {
+    pub const fn addictiveness(&self) -> f32 {
         match self {
             Effect::AntiGravity => 0.611,
             Effect::Athletic => 0.607,
@@ -993,6 +1178,327 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_effect_order() {
+        let mix = Sellable::from_product(Product::Marijuana(Effect::Calming))
+            .add_ingredient(Ingredient::Paracetamol)
+            .add_ingredient(Ingredient::Cuke)
+            .add_ingredient(Ingredient::Paracetamol);
+        assert_eq!(
+            mix.effects,
+            EffectList::from([
+                Effect::AntiGravity,
+                Effect::Balding,
+                Effect::Paranoia,
+                Effect::Sneaky,
+            ])
+        );
+    }
+
+    #[test]
+    fn test_effect_list_keys() {
+        use std::collections::HashMap;
+        use std::hash::BuildHasher;
+
+        let calm_first = EffectList::from([Effect::Calming, Effect::Toxic]);
+        let toxic_first = EffectList::from([Effect::Toxic, Effect::Calming]);
+        assert_ne!(calm_first, toxic_first);
+        let hasher = std::collections::hash_map::RandomState::new();
+        assert_eq!(hasher.hash_one(&calm_first), hasher.hash_one(&toxic_first));
+        let keys = HashMap::from([(calm_first.clone(), 1), (toxic_first, 2)]);
+        assert_eq!(keys.len(), 2);
+        assert_eq!(keys[&calm_first], 1);
+    }
+
+    #[test]
+    fn test_mix_traced() {
+        let (mix, step) = Sellable::from_product(Product::Marijuana(Effect::Calming))
+            .add_ingredient(Ingredient::Paracetamol)
+            .add_ingredient(Ingredient::Cuke)
+            .add_ingredient_traced(Ingredient::Paracetamol);
+        assert_eq!(
+            step.fired,
+            vec![
+                (Effect::Munchies, Effect::AntiGravity),
+                (Effect::Paranoia, Effect::Balding),
+                (Effect::Energizing, Effect::Paranoia),
+            ]
+        );
+        assert!(step.blocked.is_empty());
+        assert_eq!(step.new_effect, NewEffect::Added);
+        assert_eq!(step.price_delta, 26.);
+        assert_eq!(mix.sell_price(), 73.);
+
+        let (_, step) = Sellable::from_product(Product::Marijuana(Effect::Calming))
+            .add_ingredient(Ingredient::MotorOil)
+            .add_ingredient_traced(Ingredient::Paracetamol);
+        assert!(step.fired.is_empty());
+        assert_eq!(step.blocked, vec![(Effect::Calming, Effect::Slippery)]);
+        assert_eq!(step.new_effect, NewEffect::Added);
+
+        let mut mix = Sellable::from_product(Product::Meth);
+        for ingredient in [
+            Ingredient::Cuke,
+            Ingredient::Banana,
+            Ingredient::Paracetamol,
+            Ingredient::Donut,
+            Ingredient::Donut,
+            Ingredient::Battery,
+            Ingredient::Iodine,
+            Ingredient::Addy,
+            Ingredient::Gasoline,
+        ] {
+            mix = mix.add_ingredient(ingredient);
+        }
+        let (_, step) = mix.add_ingredient_traced(Ingredient::HorseSemen);
+        assert_eq!(
+            step.fired,
+            vec![(Effect::ThoughtProvoking, Effect::Electrifying)]
+        );
+        assert_eq!(step.new_effect, NewEffect::Capped);
+
+        let (_, step) = Sellable::from_product(Product::Meth)
+            .add_ingredient(Ingredient::Cuke)
+            .add_ingredient(Ingredient::Cuke)
+            .add_ingredient_traced(Ingredient::Cuke);
+        assert_eq!(step.new_effect, NewEffect::AlreadyPresent);
+        assert_eq!(step.price_delta, 0.);
+        assert!(step.is_noop());
+    }
+
+    #[test]
+    fn test_add_ingredient_recorded() {
+        let mix = Sellable::from_product(Product::Meth)
+            .add_ingredient_recorded(Ingredient::Cuke)
+            .add_ingredient_recorded(Ingredient::Cuke)
+            .add_ingredient_recorded(Ingredient::Banana);
+        assert_eq!(
+            mix.ingredients,
+            vec![Ingredient::Cuke, Ingredient::Cuke, Ingredient::Banana]
+        );
+        assert_eq!(mix.wasted, vec![1]);
+        assert!(mix.is_wasted(1));
+        assert!(!mix.is_wasted(2));
+        assert_eq!(
+            mix.effects,
+            HashSet::from([Effect::ThoughtProvoking, Effect::Gingeritis])
+        );
+        assert_eq!(mix.price(MixState::default()), 20.);
+        assert_eq!(mix.key(), "MethCukeCukeBanana");
+        let dropped = Sellable::from_product(Product::Meth)
+            .add_ingredient(Ingredient::Cuke)
+            .add_ingredient(Ingredient::Cuke)
+            .add_ingredient(Ingredient::Banana);
+        assert_eq!(dropped.price(MixState::default()), 18.);
+    }
+
+    #[test]
+    fn test_lookahead() {
+        let mix = Sellable::from_product(Product::Marijuana(Effect::Calming));
+        let lookahead = mix.lookahead(MixState::default(), false);
+        assert_eq!(lookahead.len(), Ingredient::ALL.len());
+        let addy = &lookahead[Ingredient::ALL.len() - 2];
+        assert_eq!(addy.ingredient, Ingredient::Addy);
+        assert_eq!(
+            addy.effects,
+            HashSet::from([Effect::Calming, Effect::ThoughtProvoking])
+        );
+        assert_eq!(addy.sell_price, 54.);
+        assert_eq!(
+            addy.price,
+            mix.price(MixState::default()) + Money::dollars(9)
+        );
+        assert_eq!(addy.profit_delta, 16. - 9.);
+
+        let mix = mix.add_ingredient_recorded(Ingredient::Addy);
+        let again = |keep_wasted| {
+            mix.lookahead(MixState::default(), keep_wasted)[Ingredient::ALL.len() - 2].clone()
+        };
+        assert_eq!(again(false).profit_delta, 0.);
+        assert_eq!(again(true).profit_delta, -9.);
+        assert_eq!(
+            again(true).price,
+            mix.add_ingredient_recorded(Ingredient::Addy)
+                .price(MixState::default())
+        );
+    }
+
+    #[test]
+    fn test_cost_breakdown() {
+        let state = MixState {
+            soil_quality: Quality::High,
+            ingredients: HashSet::from([OneTimeIngredient::PGR]),
+            ..MixState::default()
+        };
+        let mix = Sellable::from_product(Product::Marijuana(Effect::Calming))
+            .add_ingredient_recorded(Ingredient::Cuke)
+            .add_ingredient_recorded(Ingredient::Cuke);
+        let breakdown = mix.cost_breakdown(state.clone());
+        let items = breakdown
+            .items
+            .iter()
+            .map(|item| (item.label.as_str(), item.per_unit(), item.harvests))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            items,
+            vec![
+                ("OG Kush Seed", Money::from_f32(2.5).unwrap(), Some(1)),
+                ("Extra Long-Life Soil", Money::dollars(60) / 36., Some(3)),
+                ("PGR", Money::from_f32(2.5).unwrap(), Some(1)),
+                ("Cuke", Money::dollars(2), None),
+                ("Cuke (wasted)", Money::dollars(2), None),
+            ]
+        );
+        assert_eq!(breakdown.total(), mix.price(state));
+        let meth = Sellable::from_product(Product::Meth).cost_breakdown(MixState::default());
+        assert_eq!(
+            meth.items
+                .iter()
+                .map(|item| (item.label.as_str(), item.per_unit()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("Low Quality Pseudo", Money::dollars(6)),
+                ("Acid", Money::dollars(4)),
+                ("Red Phosphorus", Money::dollars(4)),
+            ]
+        );
+        // a tent's 12 leaves are cooked into 6 units
+        let cocaine = Sellable::from_product(Product::Cocaine).cost_breakdown(MixState::default());
+        assert_eq!(
+            cocaine
+                .items
+                .iter()
+                .map(|item| (item.label.as_str(), item.units))
+                .collect::<Vec<_>>(),
+            vec![("Coca Seed", 6.), ("Soil", 6.), ("Gasoline", 10.)]
+        );
+    }
+
+    #[test]
+    fn test_soil_prices() {
+        let mut state = MixState {
+            ingredients: HashSet::from([OneTimeIngredient::PGR]),
+            ..MixState::default()
+        };
+        let mix = Sellable::from_product(Product::Marijuana(Effect::Calming));
+        let assert_prices = |state: &MixState, expected: [f32; 3]| {
+            for ((_, price), expected) in mix.soil_prices(state.clone()).into_iter().zip(expected) {
+                assert!(
+                    (price.to_f32() - expected).abs() < 1e-3,
+                    "{price} != {expected}"
+                );
+            }
+        };
+        // seed and PGR every harvest, 12 units a harvest
+        assert_prices(
+            &state,
+            [
+                (30. + 10. + 30.) / 12.,
+                (30. + 15. + 30.) / 12.,
+                (30. + 20. + 30.) / 12.,
+            ],
+        );
+        state.grow_plan.per_harvest.clear();
+        state.grow_plan.long_life_soil_harvests = 4;
+        assert_prices(
+            &state,
+            [
+                (30. + 10. + 30.) / 12.,
+                (30. + 7.5 + 7.5) / 12.,
+                (30. + 20. + 10.) / 12.,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_margins() {
+        let state = MixState::default();
+        let meth = Sellable::from_product(Product::Meth);
+        let mix = meth.add_ingredient_recorded(Ingredient::Cuke);
+        // $85 for $14 of cook inputs and a $2 cuke
+        assert_eq!(mix.profit(state.clone()), 69.);
+        assert!((mix.margin(state.clone()) - 69. / 85. * 100.).abs() < 1e-4);
+        assert_eq!(mix.ingredient_return(state.clone()), Some(69. / 2. * 100.));
+        assert_eq!(meth.ingredient_return(state), None);
+    }
+
+    #[test]
+    fn test_placeholder_coca_yields() {
+        assert!(Product::Marijuana(Effect::Calming).is_harvest_measured());
+        assert!(!Product::Cocaine.is_harvest_measured());
+        let mix = Sellable::from_product(Product::Cocaine);
+        let mut units = vec![];
+        for (use_pot, pgr) in [(false, false), (true, false), (false, true), (true, true)] {
+            let mut state = MixState {
+                use_pot,
+                ..MixState::default()
+            };
+            if pgr {
+                state.ingredients.insert(OneTimeIngredient::PGR);
+            }
+            units.push(mix.yield_amount(state));
+        }
+        // the unit yields the placeholders were derived from
+        assert_eq!(units, vec![6., 9., 11., 16.]);
+    }
+
+    #[test]
+    fn test_packaging() {
+        let mut state = MixState::default();
+        let mix = Sellable::from_product(Product::Meth).add_ingredient_recorded(Ingredient::Cuke);
+        // $85 a unit costing $16, jars are $3
+        assert_eq!(mix.package_sell_price(Packaging::Jar), 425.);
+        assert_eq!(mix.package_price(Packaging::Jar, state.clone()), 83.);
+        assert_eq!(mix.package_profit(Packaging::Jar, state.clone()), 342.);
+        // a new game only has baggies
+        assert_eq!(mix.best_packaging(state.clone()), Packaging::Baggie);
+        state.unlocked_packaging.insert(Packaging::Jar);
+        assert_eq!(mix.best_packaging(state.clone()), Packaging::Jar);
+        state.unlocked_packaging.insert(Packaging::Brick);
+        assert_eq!(mix.best_packaging(state.clone()), Packaging::Brick);
+        state
+            .prices
+            .set(PriceItem::Packaging(Packaging::Brick), Money::dollars(30));
+        assert_eq!(mix.best_packaging(state.clone()), Packaging::Jar);
+        state
+            .prices
+            .set(PriceItem::Packaging(Packaging::Jar), Money::dollars(5));
+        assert_eq!(mix.best_packaging(state), Packaging::Baggie);
+    }
+
+    #[test]
+    fn test_profit_per_hour() {
+        let mut state = MixState::default();
+        let mix = Sellable::from_product(Product::Marijuana(Effect::Calming))
+            .add_ingredient_recorded(Ingredient::Cuke);
+        // 48 hours growing 8 units, then 8 mixes
+        assert_eq!(mix.batch_hours(state.clone()), 48.4);
+        assert_eq!(
+            mix.profit_per_hour(state.clone()),
+            mix.profit(state.clone()) / (48.4 / 8.)
+        );
+        let slow = mix.profit_per_hour(state.clone());
+        state.ingredients.insert(OneTimeIngredient::SpeedGrow);
+        assert_eq!(mix.batch_hours(state.clone()), 24.4);
+        assert!(mix.profit_per_hour(state) > slow);
+        let meth = Sellable::from_product(Product::Meth);
+        assert_eq!(meth.batch_hours(MixState::default()), 6.);
+    }
+
+    #[test]
+    fn test_quality() {
+        let mut state = MixState::default();
+        let og = Product::Marijuana(Effect::Calming);
+        assert_eq!(og.quality(&state), ProductQuality::Poor);
+        state.ingredients.insert(OneTimeIngredient::Fertilizer);
+        assert_eq!(og.quality(&state), ProductQuality::Standard);
+        state.soil_quality = Quality::High;
+        assert_eq!(Product::Cocaine.quality(&state), ProductQuality::Heavenly);
+        assert_eq!(Product::Meth.quality(&state), ProductQuality::Poor);
+        state.pseudo_quality = Quality::High;
+        assert_eq!(Product::Meth.quality(&state), ProductQuality::Premium);
+    }
+
     #[test]
     fn test_og() {
         let mix = Sellable::from_product(Product::Marijuana(Effect::Calming));
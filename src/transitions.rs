@@ -0,0 +1,167 @@
+use crate::mixing::ReactionTable;
+use crate::sellable::{EffectList, Ingredient, Product, Sellable};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{OnceLock, RwLock};
+
+/// Most transitions kept, a few tens of megabytes. Once full, new mixes are
+/// worked out every time instead of stored.
+const MAX_ENTRIES: usize = 250_000;
+
+type Shard = HashMap<Product, HashMap<EffectList, EffectList>>;
+
+/// Memoized [`ReactionTable`] mixes, keyed on the effects going in and the ingredient added.
+///
+/// Safe to share between threads, with one lock per ingredient so searches
+/// mixing different ingredients rarely wait on each other.
+#[derive(Debug)]
+pub struct TransitionCache {
+    shards: Vec<RwLock<Shard>>,
+    capacity: usize,
+    entries: AtomicUsize,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CacheStats {
+    pub entries: usize,
+    pub hits: u64,
+    pub misses: u64,
+}
+
+impl CacheStats {
+    /// Fraction of lookups answered from the cache, 0 before any lookup.
+    pub fn hit_rate(&self) -> f32 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.
+        } else {
+            self.hits as f32 / lookups as f32
+        }
+    }
+}
+
+impl Default for TransitionCache {
+    fn default() -> Self {
+        TransitionCache::with_capacity(MAX_ENTRIES)
+    }
+}
+
+impl TransitionCache {
+    fn with_capacity(capacity: usize) -> Self {
+        TransitionCache {
+            shards: Ingredient::ALL.iter().map(|_| RwLock::default()).collect(),
+            capacity,
+            entries: AtomicUsize::new(0),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// The cache [`Sellable::add_ingredient`] uses, shared by every thread.
+    pub fn shared() -> &'static TransitionCache {
+        static SHARED: OnceLock<TransitionCache> = OnceLock::new();
+        SHARED.get_or_init(TransitionCache::default)
+    }
+
+    /// The effects of `recipe` once `ingredient` is mixed in.
+    pub fn next(&self, recipe: &Sellable, ingredient: Ingredient) -> EffectList {
+        let shard = &self.shards[ingredient as usize];
+        let cached = shard
+            .read()
+            .unwrap()
+            .get(&recipe.base)
+            .and_then(|entries| entries.get(&recipe.effects))
+            .cloned();
+        if let Some(effects) = cached {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return effects;
+        }
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        let effects = recipe.mix(&ReactionTable, ingredient).effects;
+        // threads racing past the check can overshoot the cap by a few entries
+        if self.entries.load(Ordering::Relaxed) < self.capacity {
+            let mut shard = shard.write().unwrap();
+            let entries = shard.entry(recipe.base).or_default();
+            if entries
+                .insert(recipe.effects.clone(), effects.clone())
+                .is_none()
+            {
+                self.entries.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+        effects
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            entries: self.entries.load(Ordering::Relaxed),
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sellable::Effect;
+
+    #[test]
+    fn test_next() {
+        let cache = TransitionCache::default();
+        assert_eq!(cache.stats().hit_rate(), 0.);
+        let recipe = Sellable::from_product(Product::Marijuana(Effect::Calming));
+        for ingredient in Ingredient::ALL {
+            assert_eq!(
+                cache.next(&recipe, ingredient),
+                recipe.mix(&ReactionTable, ingredient).effects
+            );
+        }
+        cache.next(&recipe, Ingredient::Cuke);
+        cache.next(&Sellable::from_product(Product::Meth), Ingredient::Cuke);
+        assert_eq!(
+            cache.stats(),
+            CacheStats {
+                entries: 17,
+                hits: 1,
+                misses: 17,
+            }
+        );
+        assert_eq!(cache.stats().hit_rate(), 1. / 18.);
+    }
+
+    #[test]
+    fn test_capacity() {
+        let cache = TransitionCache::with_capacity(2);
+        let recipe = Sellable::from_product(Product::Meth);
+        for ingredient in Ingredient::ALL {
+            assert_eq!(
+                cache.next(&recipe, ingredient),
+                recipe.mix(&ReactionTable, ingredient).effects
+            );
+        }
+        cache.next(&recipe, Ingredient::Cuke);
+        cache.next(&recipe, Ingredient::Paracetamol);
+        assert_eq!(
+            cache.stats(),
+            CacheStats {
+                entries: 2,
+                hits: 1,
+                misses: 17,
+            }
+        );
+    }
+
+    #[test]
+    fn test_shared_between_threads() {
+        let cache = TransitionCache::default();
+        let recipe = Sellable::from_product(Product::Cocaine);
+        std::thread::scope(|scope| {
+            scope.spawn(|| cache.next(&recipe, Ingredient::Banana));
+        });
+        cache.next(&recipe, Ingredient::Banana);
+        assert_eq!(cache.stats().hits, 1);
+    }
+}